@@ -0,0 +1,476 @@
+//!Allocation with custom alignment
+
+use core::fmt;
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "alloc")]
+use core::{mem, ptr};
+#[cfg(feature = "alloc")]
+use core::alloc::Layout;
+
+#[cfg(feature = "alloc")]
+use crate::Deleter;
+
+#[cfg(feature = "alloc")]
+//Offset of value within allocation, that leaves room for alignment header.
+#[inline(always)]
+const fn value_offset(align: usize) -> usize {
+    //Both are powers of two, therefore bigger one is multiple of smaller one.
+    if align > mem::size_of::<usize>() {
+        align
+    } else {
+        mem::size_of::<usize>()
+    }
+}
+
+#[cfg(feature = "alloc")]
+///Moves value into memory, allocated by global allocator with alignment of at least `align`.
+///
+///Alignment is stored right before value, to be retrieved by `AlignedDeleter`.
+pub(crate) fn try_boxed_raw<T>(val: T, align: usize) -> Result<*mut T, crate::Error> {
+    let layout = aligned_layout::<T>(align);
+    let offset = value_offset(layout.align());
+
+    unsafe {
+        let base = alloc::alloc::alloc(layout);
+        if base.is_null() {
+            return Err(crate::Error::AllocFailed);
+        }
+
+        let result = base.add(offset) as *mut T;
+        (result as *mut usize).sub(1).write(layout.align());
+        result.write(val);
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+///Infallible version of `try_boxed_raw`, which invokes `handle_alloc_error` on failure.
+pub(crate) fn boxed_raw<T>(val: T, align: usize) -> *mut T {
+    match try_boxed_raw(val, align) {
+        Ok(ptr) => ptr,
+        Err(_) => alloc::alloc::handle_alloc_error(aligned_layout::<T>(align)),
+    }
+}
+
+#[cfg(feature = "alloc")]
+//Layout of allocation for `T` with alignment header.
+fn aligned_layout<T>(align: usize) -> Layout {
+    assert!(align.is_power_of_two(), "Alignment must be power of two");
+    //Header must be aligned too
+    let align = align.max(mem::align_of::<T>()).max(mem::align_of::<usize>());
+    let offset = value_offset(align);
+    Layout::from_size_align(offset + mem::size_of::<T>(), align).expect("Invalid layout")
+}
+
+#[derive(Default)]
+///Deleter for values, allocated with custom alignment by
+///[boxed_aligned](../unique/struct.Unique.html#method.boxed_aligned).
+///
+///Invokes destructor and de-allocates memory using global allocator, with alignment stored
+///alongside the value.
+pub struct AlignedDeleter;
+
+#[cfg(feature = "alloc")]
+impl Deleter for AlignedDeleter {
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        debug_assert!(!ptr.is_null());
+
+        let size = mem::size_of_val(&*ptr);
+        let align = (ptr as *mut u8 as *mut usize).sub(1).read();
+        let offset = value_offset(align);
+        ptr::drop_in_place(ptr);
+
+        alloc::alloc::dealloc((ptr as *mut u8).sub(offset), Layout::from_size_align_unchecked(offset + size, align));
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", any(target_os = "macos", target_os = "ios")))]
+///Size of memory page, which is assumed by [page_size](fn.page_size.html), when it cannot be
+///queried from OS (e.g. without `std` feature or on unsupported platform).
+pub const PAGE_SIZE: usize = 16384;
+#[cfg(not(all(target_arch = "aarch64", any(target_os = "macos", target_os = "ios"))))]
+///Size of memory page, which is assumed by [page_size](fn.page_size.html), when it cannot be
+///queried from OS (e.g. without `std` feature or on unsupported platform).
+pub const PAGE_SIZE: usize = 4096;
+
+#[cfg(all(feature = "std", any(target_os = "linux", target_os = "macos", target_os = "ios")))]
+mod sys {
+    use core::ffi::{c_int, c_long};
+
+    #[cfg(target_os = "linux")]
+    const SC_PAGESIZE: c_int = 30;
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    const SC_PAGESIZE: c_int = 29;
+
+    extern "C" {
+        fn sysconf(name: c_int) -> c_long;
+    }
+
+    pub fn page_size() -> Option<usize> {
+        match unsafe { sysconf(SC_PAGESIZE) } {
+            size if size > 0 => Some(size as usize),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(all(feature = "std", windows))]
+mod sys {
+    use core::ffi::c_void;
+    use core::mem;
+
+    #[repr(C)]
+    #[allow(non_snake_case)]
+    struct SYSTEM_INFO {
+        dwOemId: u32,
+        dwPageSize: u32,
+        lpMinimumApplicationAddress: *mut c_void,
+        lpMaximumApplicationAddress: *mut c_void,
+        dwActiveProcessorMask: usize,
+        dwNumberOfProcessors: u32,
+        dwProcessorType: u32,
+        dwAllocationGranularity: u32,
+        wProcessorLevel: u16,
+        wProcessorRevision: u16,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetSystemInfo(info: *mut SYSTEM_INFO);
+    }
+
+    pub fn page_size() -> Option<usize> {
+        let mut info = mem::MaybeUninit::<SYSTEM_INFO>::uninit();
+        let size = unsafe {
+            GetSystemInfo(info.as_mut_ptr());
+            info.assume_init().dwPageSize
+        };
+        match size {
+            0 => None,
+            size => Some(size as usize),
+        }
+    }
+}
+
+#[cfg(not(all(feature = "std", any(target_os = "linux", target_os = "macos", target_os = "ios", windows))))]
+mod sys {
+    #[inline(always)]
+    pub fn page_size() -> Option<usize> {
+        None
+    }
+}
+
+static CACHED_PAGE_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+///Returns size of memory page, used by [PageDeleter](struct.PageDeleter.html).
+///
+///It is queried from OS once, via `sysconf` on Linux and macOS or `GetSystemInfo` on Windows,
+///requiring `std` feature. Otherwise [PAGE_SIZE](constant.PAGE_SIZE.html) is assumed.
+pub fn page_size() -> usize {
+    match CACHED_PAGE_SIZE.load(Ordering::Relaxed) {
+        0 => {
+            let size = match sys::page_size() {
+                Some(size) if size.is_power_of_two() => size,
+                _ => PAGE_SIZE,
+            };
+            CACHED_PAGE_SIZE.store(size, Ordering::Relaxed);
+            size
+        },
+        size => size,
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+//Layout of whole pages, which are able to fit `size` bytes.
+fn try_page_layout(size: usize) -> Option<Layout> {
+    let page_size = page_size();
+    let size = match size {
+        0 => page_size,
+        size => size.checked_add(page_size - 1)? & !(page_size - 1),
+    };
+    Layout::from_size_align(size, page_size).ok()
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+fn page_layout(size: usize) -> Layout {
+    try_page_layout(size).expect("capacity overflow")
+}
+
+#[cfg(feature = "alloc")]
+///Allocates whole pages, which are able to fit `size` bytes.
+pub(crate) fn try_alloc_pages(size: usize, zeroed: bool) -> Result<*mut u8, crate::Error> {
+    let layout = try_page_layout(size).ok_or(crate::Error::AllocFailed)?;
+    let ptr = unsafe {
+        match zeroed {
+            true => alloc::alloc::alloc_zeroed(layout),
+            false => alloc::alloc::alloc(layout),
+        }
+    };
+    match ptr.is_null() {
+        true => Err(crate::Error::AllocFailed),
+        false => Ok(ptr),
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+///Infallible version of `try_alloc_pages`, which invokes `handle_alloc_error` on failure.
+pub(crate) fn alloc_pages(size: usize, zeroed: bool) -> *mut u8 {
+    match try_alloc_pages(size, zeroed) {
+        Ok(ptr) => ptr,
+        Err(_) => alloc::alloc::handle_alloc_error(page_layout(size)),
+    }
+}
+
+#[derive(Default)]
+///Deleter for values, allocated in whole pages by
+///[boxed_pages](../unique/struct.Unique.html#method.boxed_pages).
+///
+///Invokes destructor and de-allocates pages using global allocator.
+pub struct PageDeleter;
+
+#[cfg(feature = "alloc")]
+impl Deleter for PageDeleter {
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        debug_assert!(!ptr.is_null());
+
+        let layout = page_layout(mem::size_of_val(&*ptr));
+        ptr::drop_in_place(ptr);
+        alloc::alloc::dealloc(ptr as *mut u8, layout);
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(any(target_arch = "x86_64", target_arch = "aarch64", target_arch = "powerpc64"), repr(align(128)))]
+#[cfg_attr(not(any(target_arch = "x86_64", target_arch = "aarch64", target_arch = "powerpc64")), repr(align(64)))]
+///Value, aligned and padded to the size of cache line.
+///
+///Prevents false sharing between values, modified by different threads.
+///
+///On `x86_64`, `aarch64` and `powerpc64` it uses 128 bytes, as adjacent cache lines are prefetched in pairs,
+///otherwise 64 bytes.
+pub struct CachePadded<T> {
+    value: T,
+}
+
+impl<T> CachePadded<T> {
+    #[inline(always)]
+    ///Creates new instance.
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns inner value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> core::ops::Deref for CachePadded<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> core::ops::DerefMut for CachePadded<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CachePadded<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, fmt)
+    }
+}
+
+impl<T> From<T> for CachePadded<T> {
+    #[inline(always)]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+///Owned byte buffer with caller chosen alignment, allocated by global allocator.
+///
+///Buffer is filled with zero bytes on creation and never moves, therefore its address can be
+///registered with DMA engines or `io_uring`, which require stronger alignment than `Vec<u8>` provides.
+///
+///On `Drop` memory is de-allocated with original layout.
+///
+///```rust
+///use smart_ptr::aligned::AlignedBuffer;
+///
+///let mut buffer = AlignedBuffer::new(512, 4096);
+///assert_eq!(buffer.len(), 512);
+///assert_eq!(buffer.as_ptr() as usize % 4096, 0);
+///buffer[0] = 1;
+///assert_eq!(buffer[0], 1);
+///```
+pub struct AlignedBuffer {
+    ptr: ptr::NonNull<u8>,
+    layout: Layout,
+}
+
+#[cfg(feature = "alloc")]
+impl AlignedBuffer {
+    #[inline]
+    ///Creates new buffer of `len` bytes, aligned to `align`.
+    ///
+    ///# Panics
+    ///
+    ///- If `align` is not power of two.
+    ///- If `len` overflows, when rounded up to `align`.
+    pub fn new(len: usize, align: usize) -> Self {
+        let layout = Self::layout_for(len, align).expect("Invalid layout");
+        match Self::with_layout(layout) {
+            Ok(buffer) => buffer,
+            Err(_) => alloc::alloc::handle_alloc_error(layout),
+        }
+    }
+
+    #[inline]
+    ///Fallible version of [new](#method.new), returning `Error::AllocFailed` if allocation fails.
+    ///
+    ///# Panics
+    ///
+    ///- If `align` is not power of two.
+    pub fn try_new(len: usize, align: usize) -> Result<Self, crate::Error> {
+        let layout = Self::layout_for(len, align).ok_or(crate::Error::AllocFailed)?;
+        Self::with_layout(layout)
+    }
+
+    #[inline]
+    fn layout_for(len: usize, align: usize) -> Option<Layout> {
+        assert!(align.is_power_of_two(), "Alignment must be power of two");
+        Layout::from_size_align(len, align).ok()
+    }
+
+    fn with_layout(layout: Layout) -> Result<Self, crate::Error> {
+        //Zero sized buffers are never allocated
+        let ptr = match layout.size() {
+            0 => layout.align() as *mut u8,
+            _ => unsafe {
+                alloc::alloc::alloc_zeroed(layout)
+            },
+        };
+
+        match ptr::NonNull::new(ptr) {
+            Some(ptr) => Ok(Self {
+                ptr,
+                layout,
+            }),
+            None => Err(crate::Error::AllocFailed),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of bytes in buffer.
+    pub fn len(&self) -> usize {
+        self.layout.size()
+    }
+
+    #[inline(always)]
+    ///Returns whether buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline(always)]
+    ///Returns alignment of buffer.
+    pub fn align(&self) -> usize {
+        self.layout.align()
+    }
+
+    #[inline(always)]
+    ///Returns layout of buffer's allocation.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer.
+    pub fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer.
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    ///Gets buffer as slice of bytes.
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe {
+            core::slice::from_raw_parts(self.ptr.as_ptr(), self.len())
+        }
+    }
+
+    #[inline(always)]
+    ///Gets buffer as mutable slice of bytes.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe {
+            core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len())
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for AlignedBuffer {
+    #[inline]
+    fn drop(&mut self) {
+        if self.layout.size() != 0 {
+            unsafe {
+                alloc::alloc::dealloc(self.ptr.as_ptr(), self.layout);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::ops::Deref for AlignedBuffer {
+    type Target = [u8];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::ops::DerefMut for AlignedBuffer {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for AlignedBuffer {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), fmt)
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl Send for AlignedBuffer {}
+
+#[cfg(feature = "alloc")]
+unsafe impl Sync for AlignedBuffer {}
@@ -0,0 +1,187 @@
+//!Bump arena integration
+
+use core::ptr;
+#[cfg(feature = "alloc")]
+use core::{cell, mem};
+#[cfg(feature = "alloc")]
+use core::alloc::Layout;
+
+use crate::Deleter;
+
+#[derive(Default)]
+///Deleter for objects, placed in arena.
+///
+///It only runs destructor of the object, while its memory is re-used once arena is reset.
+pub struct ArenaDeleter;
+
+impl Deleter for ArenaDeleter {
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        debug_assert!(!ptr.is_null());
+
+        ptr::drop_in_place(ptr);
+    }
+}
+
+#[cfg(feature = "alloc")]
+const CHUNK_SIZE: usize = 4096;
+#[cfg(feature = "alloc")]
+const CHUNK_ALIGN: usize = 16;
+
+#[cfg(feature = "alloc")]
+///Minimal bump allocator, which allocates memory from chunks of global allocator.
+///
+///Objects are placed into arena with [ArenaDeleter](struct.ArenaDeleter.html), therefore their
+///destructors run on `Drop`, but memory is only freed once arena is reset or dropped.
+///
+///```rust
+///use smart_ptr::arena::Arena;
+///
+///let mut arena = Arena::new();
+///{
+///    let first = arena.boxed(String::from("first"));
+///    let second = arena.boxed(2u64);
+///    assert_eq!(*first, "first");
+///    assert_eq!(*second, 2);
+///}
+///arena.reset();
+///```
+pub struct Arena {
+    chunks: cell::RefCell<alloc::vec::Vec<(ptr::NonNull<u8>, Layout)>>,
+    cursor: cell::Cell<usize>,
+    end: cell::Cell<usize>,
+    chunk_size: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl Arena {
+    #[inline]
+    ///Creates new empty arena with default chunk size.
+    pub const fn new() -> Self {
+        Self::with_chunk_size(CHUNK_SIZE)
+    }
+
+    #[inline]
+    ///Creates new empty arena, which allocates chunks of at least `chunk_size` bytes.
+    pub const fn with_chunk_size(chunk_size: usize) -> Self {
+        Self {
+            chunks: cell::RefCell::new(alloc::vec::Vec::new()),
+            cursor: cell::Cell::new(0),
+            end: cell::Cell::new(0),
+            chunk_size,
+        }
+    }
+
+    ///Allocates uninitialized memory for `layout`.
+    ///
+    ///Zero sized layouts are not allocated, instead dangling pointer is returned.
+    pub fn alloc_layout(&self, layout: Layout) -> ptr::NonNull<u8> {
+        if layout.size() == 0 {
+            //Alignment is always non-zero
+            return unsafe {
+                ptr::NonNull::new_unchecked(layout.align() as *mut u8)
+            };
+        }
+
+        let align_mask = layout.align() - 1;
+        let start = (self.cursor.get() + align_mask) & !align_mask;
+        let start = match start.checked_add(layout.size()) {
+            Some(end) if self.cursor.get() != 0 && end <= self.end.get() => start,
+            _ => self.grow(layout),
+        };
+
+        self.cursor.set(start + layout.size());
+        unsafe {
+            ptr::NonNull::new_unchecked(start as *mut u8)
+        }
+    }
+
+    #[cold]
+    //Allocates new chunk, big enough for `layout`, returning aligned address within it.
+    fn grow(&self, layout: Layout) -> usize {
+        let size = layout.size().checked_add(layout.align()).expect("capacity overflow");
+        let size = core::cmp::max(size, self.chunk_size);
+        let chunk = Layout::from_size_align(size, CHUNK_ALIGN).expect("capacity overflow");
+
+        let ptr = unsafe {
+            alloc::alloc::alloc(chunk)
+        };
+        let ptr = match ptr::NonNull::new(ptr) {
+            Some(ptr) => ptr,
+            None => alloc::alloc::handle_alloc_error(chunk),
+        };
+        self.chunks.borrow_mut().push((ptr, chunk));
+
+        let align_mask = layout.align() - 1;
+        let cursor = ptr.as_ptr() as usize;
+        self.end.set(cursor + size);
+        (cursor + align_mask) & !align_mask
+    }
+
+    #[inline]
+    ///Moves value into arena.
+    pub fn boxed<T>(&self, val: T) -> crate::Unique<'_, T, ArenaDeleter> {
+        let ptr = self.alloc_layout(Layout::new::<T>()).cast::<T>().as_ptr();
+        unsafe {
+            ptr.write(val);
+            crate::Unique::from_ptr_unchecked(ptr)
+        }
+    }
+
+    ///Copies slice into arena.
+    pub fn copy_slice<T: Copy>(&self, val: &[T]) -> crate::Unique<'_, [T], ArenaDeleter> {
+        let layout = Layout::for_value(val);
+        let ptr = self.alloc_layout(layout).cast::<T>().as_ptr();
+        unsafe {
+            ptr::copy_nonoverlapping(val.as_ptr(), ptr, val.len());
+            crate::Unique::from_ptr_unchecked(ptr::slice_from_raw_parts_mut(ptr, val.len()))
+        }
+    }
+
+    #[inline]
+    ///Copies string into arena.
+    pub fn copy_str(&self, val: &str) -> crate::Unique<'_, str, ArenaDeleter> {
+        let ptr = self.copy_slice(val.as_bytes()).release();
+        unsafe {
+            crate::Unique::from_ptr_unchecked(ptr.as_ptr() as *mut str)
+        }
+    }
+
+    #[inline]
+    ///Returns number of bytes, allocated by arena from global allocator.
+    pub fn capacity(&self) -> usize {
+        self.chunks.borrow().iter().map(|(_, layout)| layout.size()).sum()
+    }
+
+    ///Frees all memory of the arena.
+    ///
+    ///It requires exclusive access, therefore no object can be alive within arena.
+    pub fn reset(&mut self) {
+        for (ptr, layout) in mem::take(self.chunks.get_mut()) {
+            unsafe {
+                alloc::alloc::dealloc(ptr.as_ptr(), layout);
+            }
+        }
+        self.cursor.set(0);
+        self.end.set(0);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for Arena {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for Arena {
+    #[inline]
+    fn drop(&mut self) {
+        self.reset();
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl Send for Arena {}
@@ -0,0 +1,170 @@
+//!Atomic owning pointer implementation
+
+use core::{mem, fmt, ptr, marker};
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::{Deleter, Unique};
+
+///Atomic container of optional owned pointer.
+///
+///Allows to publish and replace pointer across threads without locking.
+///
+///Container never hands out references to the value, therefore replaced pointer
+///can be returned or disposed of safely.
+///
+///```rust
+///use smart_ptr::{AtomicUnique, Unique};
+///
+///let mut first = 1u32;
+///let mut second = 2u32;
+///let atomic = AtomicUnique::<u32, ()>::new(Some((&mut first).into()));
+///let old = atomic.swap(Some((&mut second).into())).expect("to have value");
+///assert_eq!(*old, 1);
+///assert_eq!(*atomic.take().expect("to have value"), 2);
+///assert!(atomic.is_none());
+///```
+pub struct AtomicUnique<'a, T, D: Deleter> {
+    inner: AtomicPtr<T>,
+    _traits: marker::PhantomData<Unique<'a, T, D>>,
+}
+
+#[inline(always)]
+fn into_raw<T, D: Deleter>(ptr: Option<Unique<'_, T, D>>) -> *mut T {
+    match ptr {
+        Some(ptr) => ptr.release().as_ptr(),
+        None => ptr::null_mut(),
+    }
+}
+
+impl<'a, T, D: Deleter> AtomicUnique<'a, T, D> {
+    #[inline(always)]
+    ///Creates new empty container.
+    pub const fn empty() -> Self {
+        Self {
+            inner: AtomicPtr::new(ptr::null_mut()),
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Creates new container with provided pointer.
+    pub fn new(ptr: Option<Unique<'a, T, D>>) -> Self {
+        Self {
+            inner: AtomicPtr::new(into_raw(ptr)),
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Returns whether container is empty.
+    pub fn is_none(&self) -> bool {
+        self.inner.load(Ordering::Acquire).is_null()
+    }
+
+    #[inline]
+    ///Gets currently stored raw pointer.
+    ///
+    ///Note that pointer can be replaced and disposed of by other thread at any time.
+    pub fn as_ptr(&self) -> *mut T {
+        self.inner.load(Ordering::Acquire)
+    }
+
+    #[inline]
+    ///Stores new pointer, returning previous one.
+    pub fn swap(&self, ptr: Option<Unique<'a, T, D>>) -> Option<Unique<'a, T, D>> {
+        let old = self.inner.swap(into_raw(ptr), Ordering::AcqRel);
+        unsafe {
+            Unique::from_ptr(old)
+        }
+    }
+
+    #[inline]
+    ///Stores new pointer, disposing of previous one.
+    pub fn store(&self, ptr: Option<Unique<'a, T, D>>) {
+        let _ = self.swap(ptr);
+    }
+
+    #[inline]
+    ///Takes pointer out of container, leaving it empty.
+    pub fn take(&self) -> Option<Unique<'a, T, D>> {
+        self.swap(None)
+    }
+
+    ///Stores new pointer, if currently stored pointer is the same as `current`.
+    ///
+    ///On success returns previous pointer, otherwise returns `new` back.
+    #[allow(clippy::type_complexity)]
+    pub fn compare_exchange(&self, current: *mut T, new: Option<Unique<'a, T, D>>) -> Result<Option<Unique<'a, T, D>>, Option<Unique<'a, T, D>>> {
+        //Ownership is given up before publishing pointer, so that it is not owned twice once
+        //other thread takes it.
+        let new_ptr = into_raw(new);
+
+        match self.inner.compare_exchange(current, new_ptr, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(old) => Ok(unsafe {
+                Unique::from_ptr(old)
+            }),
+            Err(_) => Err(unsafe {
+                Unique::from_ptr(new_ptr)
+            }),
+        }
+    }
+
+    #[inline]
+    ///Gets mutable reference to the value, if any.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        unsafe {
+            self.inner.get_mut().as_mut()
+        }
+    }
+
+    #[inline]
+    ///Consumes container, returning stored pointer.
+    pub fn into_inner(mut self) -> Option<Unique<'a, T, D>> {
+        let ptr = mem::replace(self.inner.get_mut(), ptr::null_mut());
+        unsafe {
+            Unique::from_ptr(ptr)
+        }
+    }
+}
+
+impl<'a, T, D: Deleter> Default for AtomicUnique<'a, T, D> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<'a, T, D: Deleter> From<Unique<'a, T, D>> for AtomicUnique<'a, T, D> {
+    #[inline(always)]
+    fn from(ptr: Unique<'a, T, D>) -> Self {
+        Self::new(Some(ptr))
+    }
+}
+
+impl<'a, T, D: Deleter> Drop for AtomicUnique<'a, T, D> {
+    #[inline]
+    fn drop(&mut self) {
+        let ptr = mem::replace(self.inner.get_mut(), ptr::null_mut());
+        unsafe {
+            let _ = Unique::<T, D>::from_ptr(ptr);
+        }
+    }
+}
+
+impl<'a, T, D: Deleter> fmt::Pointer for AtomicUnique<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.as_ptr(), fmt)
+    }
+}
+
+impl<'a, T, D: Deleter> fmt::Debug for AtomicUnique<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.as_ptr(), fmt)
+    }
+}
+
+unsafe impl<'a, T: Send, D: Deleter> Send for AtomicUnique<'a, T, D> {}
+
+unsafe impl<'a, T: Send, D: Deleter> Sync for AtomicUnique<'a, T, D> {}
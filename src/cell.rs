@@ -0,0 +1,205 @@
+//!Unique pointer with interior mutability
+
+use core::fmt;
+use core::cell::Cell;
+
+use crate::{Deleter, Unique};
+
+//Borrow flag value, indicating mutable borrow.
+const WRITING: isize = -1;
+
+///Smart pointer with runtime checked borrowing, similar to `RefCell`.
+///
+///Allows to mutate pointee from shared contexts (e.g. callbacks) without resorting to raw
+///pointers. Not thread-safe.
+///
+///```rust
+///use smart_ptr::{UniqueCell, Unique};
+///
+///let mut value = 0u32;
+///let cell = UniqueCell::new(Unique::<u32, ()>::from(&mut value));
+///let callback = || *cell.borrow_mut() += 1;
+///callback();
+///callback();
+///assert_eq!(*cell.borrow(), 2);
+///```
+pub struct UniqueCell<'a, T: ?Sized, D: Deleter> {
+    borrow: Cell<isize>,
+    inner: Unique<'a, T, D>,
+}
+
+impl<'a, T: ?Sized, D: Deleter> UniqueCell<'a, T, D> {
+    #[inline]
+    ///Creates new cell with provided pointer.
+    pub fn new(ptr: Unique<'a, T, D>) -> Self {
+        Self {
+            borrow: Cell::new(0),
+            inner: ptr,
+        }
+    }
+
+    #[inline]
+    ///Acquires shared reference.
+    ///
+    ///Returns `None` if it is mutably borrowed.
+    pub fn try_borrow(&self) -> Option<Ref<'_, T>> {
+        match self.borrow.get() {
+            WRITING | isize::MAX => None,
+            count => {
+                self.borrow.set(count + 1);
+                Some(Ref {
+                    value: self.inner.get(),
+                    borrow: &self.borrow,
+                })
+            },
+        }
+    }
+
+    #[inline]
+    ///Acquires mutable reference.
+    ///
+    ///Returns `None` if it is already borrowed.
+    pub fn try_borrow_mut(&self) -> Option<RefMut<'_, T>> {
+        match self.borrow.get() {
+            0 => {
+                self.borrow.set(WRITING);
+                Some(RefMut {
+                    value: self.inner.get(),
+                    borrow: &self.borrow,
+                })
+            },
+            _ => None,
+        }
+    }
+
+    #[inline]
+    ///Acquires shared reference.
+    ///
+    ///# Panics
+    ///
+    ///- If it is mutably borrowed.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        match self.try_borrow() {
+            Some(result) => result,
+            None => panic!("UniqueCell is already mutably borrowed"),
+        }
+    }
+
+    #[inline]
+    ///Acquires mutable reference.
+    ///
+    ///# Panics
+    ///
+    ///- If it is already borrowed.
+    pub fn borrow_mut(&self) -> RefMut<'_, T> {
+        match self.try_borrow_mut() {
+            Some(result) => result,
+            None => panic!("UniqueCell is already borrowed"),
+        }
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer, without affecting borrow state.
+    pub fn as_ptr(&self) -> *mut T {
+        self.inner.get()
+    }
+
+    #[inline(always)]
+    ///Gets mutable reference, which is statically guaranteed to be unique.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    #[inline(always)]
+    ///Consumes cell, returning stored pointer.
+    pub fn into_inner(self) -> Unique<'a, T, D> {
+        self.inner
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> From<Unique<'a, T, D>> for UniqueCell<'a, T, D> {
+    #[inline(always)]
+    fn from(ptr: Unique<'a, T, D>) -> Self {
+        Self::new(ptr)
+    }
+}
+
+impl<'a, T: ?Sized + fmt::Debug, D: Deleter> fmt::Debug for UniqueCell<'a, T, D> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.try_borrow() {
+            Some(value) => fmt.debug_struct("UniqueCell").field("value", &&*value).finish(),
+            None => fmt.debug_struct("UniqueCell").field("value", &format_args!("<borrowed>")).finish(),
+        }
+    }
+}
+
+///Shared reference to value of [UniqueCell](struct.UniqueCell.html).
+pub struct Ref<'c, T: ?Sized> {
+    value: *const T,
+    borrow: &'c Cell<isize>,
+}
+
+impl<'c, T: ?Sized> core::ops::Deref for Ref<'c, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            &*self.value
+        }
+    }
+}
+
+impl<'c, T: ?Sized> Drop for Ref<'c, T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.borrow.set(self.borrow.get() - 1);
+    }
+}
+
+impl<'c, T: ?Sized + fmt::Debug> fmt::Debug for Ref<'c, T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, fmt)
+    }
+}
+
+///Mutable reference to value of [UniqueCell](struct.UniqueCell.html).
+pub struct RefMut<'c, T: ?Sized> {
+    value: *mut T,
+    borrow: &'c Cell<isize>,
+}
+
+impl<'c, T: ?Sized> core::ops::Deref for RefMut<'c, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            &*self.value
+        }
+    }
+}
+
+impl<'c, T: ?Sized> core::ops::DerefMut for RefMut<'c, T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe {
+            &mut *self.value
+        }
+    }
+}
+
+impl<'c, T: ?Sized> Drop for RefMut<'c, T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.borrow.set(0);
+    }
+}
+
+impl<'c, T: ?Sized + fmt::Debug> fmt::Debug for RefMut<'c, T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, fmt)
+    }
+}
@@ -0,0 +1,198 @@
+//!COM-style interface pointer implementation
+
+use core::{mem, fmt, ptr};
+
+///Describes reference counting routines of COM-like interface.
+///
+///Usually it is implemented by accessing object's virtual table and invoking
+///its `AddRef` and `Release` functions.
+///
+///```rust
+///use smart_ptr::com::Interface;
+///
+///#[repr(C)]
+///struct IFooVtbl {
+///    add_ref: unsafe extern "C" fn(*mut IFoo) -> u32,
+///    release: unsafe extern "C" fn(*mut IFoo) -> u32,
+///}
+///
+///#[repr(C)]
+///struct IFoo {
+///    vtbl: *const IFooVtbl,
+///}
+///
+///unsafe impl Interface for IFoo {
+///    unsafe fn add_ref(this: *mut Self) {
+///        ((*(*this).vtbl).add_ref)(this);
+///    }
+///
+///    unsafe fn release(this: *mut Self) {
+///        ((*(*this).vtbl).release)(this);
+///    }
+///}
+///```
+///
+///# Safety
+///
+///Implementation must guarantee that object remains alive as long as there is at least one
+///reference to it, and that `release` disposes of object once last reference is gone.
+pub unsafe trait Interface {
+    ///Increments reference count of the object.
+    unsafe fn add_ref(this: *mut Self);
+    ///Decrements reference count of the object, destroying it once count reaches zero.
+    unsafe fn release(this: *mut Self);
+}
+
+#[repr(transparent)]
+///Reference counted pointer to COM-like interface.
+///
+///On `Clone` it calls `AddRef`, while on `Drop` it calls `Release`.
+///
+///Pointer owns single reference to the object.
+pub struct ComPtr<T: ?Sized + Interface> {
+    inner: ptr::NonNull<T>,
+}
+
+impl<T: ?Sized + Interface> ComPtr<T> {
+    #[inline]
+    ///Creates new instance from raw pointer, taking ownership of existing reference.
+    ///
+    ///# Panics
+    ///
+    ///- If pointer is null
+    pub unsafe fn new(ptr: *mut T) -> Self {
+        assert!(!ptr.is_null());
+
+        Self::from_ptr_unchecked(ptr)
+    }
+
+    #[inline]
+    ///Creates instance from raw pointer, taking ownership of existing reference.
+    ///
+    ///Returns `None` if pointer is null.
+    pub unsafe fn from_ptr(ptr: *mut T) -> Option<Self> {
+        match ptr.is_null() {
+            true => None,
+            false => Some(Self::from_ptr_unchecked(ptr)),
+        }
+    }
+
+    #[inline]
+    ///Creates instance from raw pointer, without checking if pointer is null.
+    ///
+    ///User must ensure that pointer is non-null
+    pub unsafe fn from_ptr_unchecked(ptr: *mut T) -> Self {
+        Self {
+            inner: ptr::NonNull::new_unchecked(ptr),
+        }
+    }
+
+    #[inline]
+    ///Creates instance from borrowed raw pointer, acquiring new reference via `AddRef`.
+    ///
+    ///Returns `None` if pointer is null.
+    pub unsafe fn from_ptr_borrowed(ptr: *mut T) -> Option<Self> {
+        match ptr.is_null() {
+            true => None,
+            false => {
+                T::add_ref(ptr);
+                Some(Self::from_ptr_unchecked(ptr))
+            }
+        }
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer, without affecting reference count.
+    pub fn get(&self) -> *mut T {
+        self.inner.as_ptr()
+    }
+
+    #[inline(always)]
+    ///Gets reference to underlying interface.
+    pub fn as_ref(&self) -> &T {
+        self
+    }
+
+    #[inline(always)]
+    ///Swaps underlying pointers between instances
+    pub fn swap(&mut self, other: &mut Self) {
+        mem::swap(&mut self.inner, &mut other.inner);
+    }
+
+    #[inline]
+    ///Releases the ownership and returns raw pointer, without calling `Release`.
+    ///
+    ///Caller becomes responsible for owned reference.
+    pub fn release(self) -> ptr::NonNull<T> {
+        let result = self.inner;
+        mem::forget(self);
+        result
+    }
+}
+
+impl<T: ?Sized + Interface> Clone for ComPtr<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            T::add_ref(self.inner.as_ptr());
+        }
+
+        Self {
+            inner: self.inner,
+        }
+    }
+}
+
+impl<T: ?Sized + Interface> Drop for ComPtr<T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        unsafe {
+            T::release(self.inner.as_ptr())
+        }
+    }
+}
+
+impl<T: ?Sized + Interface> core::ops::Deref for ComPtr<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            &*self.inner.as_ptr()
+        }
+    }
+}
+
+impl<T: ?Sized + Interface> fmt::Pointer for ComPtr<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.inner, fmt)
+    }
+}
+
+impl<T: ?Sized + Interface> fmt::Debug for ComPtr<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.inner, fmt)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T: ?Sized + Interface> defmt::Format for ComPtr<T> {
+    #[inline(always)]
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(fmt, "{=str}@{=usize:#x}", core::any::type_name::<T>(), self.inner.as_ptr() as *const u8 as usize)
+    }
+}
+
+impl<T: ?Sized + Interface> PartialEq<Self> for ComPtr<T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.inner.as_ptr(), other.inner.as_ptr())
+    }
+}
+
+impl<T: ?Sized + Interface> Eq for ComPtr<T> {
+}
+
+impl<T: ?Sized + Interface> Unpin for ComPtr<T> {}
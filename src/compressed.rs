@@ -0,0 +1,283 @@
+//!Compressed pointer implementation
+//!
+//!Pointer is stored as 32-bit offset from base address, provided by [PtrBase](trait.PtrBase.html),
+//!halving its size on 64-bit targets, which is useful for cache-dense data structures, placed
+//!within single region (e.g. arena or pre-allocated heap).
+
+use core::{fmt, ptr, marker};
+use core::num::NonZeroU32;
+use core::convert::TryFrom;
+
+use crate::Deleter;
+
+///Describes base address of the region, pointers are compressed against.
+pub trait PtrBase {
+    ///Returns base address.
+    ///
+    ///It must remain the same for lifetime of all pointers, compressed against it.
+    fn base() -> *mut u8;
+}
+
+#[inline]
+//Encodes `ptr` as offset from base plus one, reserving zero for null pointer.
+fn compress<B: PtrBase>(ptr: *const u8) -> Option<NonZeroU32> {
+    let offset = (ptr as usize).checked_sub(B::base() as usize)?;
+    let offset = u32::try_from(offset).ok()?;
+    NonZeroU32::new(offset.wrapping_add(1))
+}
+
+#[inline(always)]
+fn decompress<B: PtrBase>(encoded: NonZeroU32) -> *mut u8 {
+    B::base().wrapping_add(encoded.get() as usize - 1)
+}
+
+#[repr(transparent)]
+///Pointer, that stores 32-bit offset from base address of `B`.
+///
+///Pointer is decompressed on each access, by adding offset to the base address.
+///
+///```rust
+///use smart_ptr::compressed::{CompressedPtr, PtrBase};
+///
+///struct Region;
+///
+///static mut REGION: [u64; 16] = [0; 16];
+///
+///impl PtrBase for Region {
+///    fn base() -> *mut u8 {
+///        unsafe { core::ptr::addr_of_mut!(REGION) as *mut u8 }
+///    }
+///}
+///
+///let elem = unsafe { core::ptr::addr_of_mut!(REGION[2]) };
+///let ptr = CompressedPtr::<u64, Region>::new(elem).expect("to be within region");
+///assert_eq!(core::mem::size_of_val(&ptr), 4);
+///assert_eq!(ptr.offset(), Some(16));
+///assert_eq!(ptr.get(), elem);
+///```
+pub struct CompressedPtr<T, B: PtrBase> {
+    encoded: Option<NonZeroU32>,
+    _traits: marker::PhantomData<(*mut T, B)>,
+}
+
+impl<T, B: PtrBase> CompressedPtr<T, B> {
+    #[inline(always)]
+    ///Creates null pointer.
+    pub const fn null() -> Self {
+        Self {
+            encoded: None,
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Compresses `ptr`.
+    ///
+    ///Null `ptr` results in null pointer.
+    ///
+    ///Returns `None` if pointer is below base address or its offset doesn't fit into 32 bits.
+    pub fn new(ptr: *mut T) -> Option<Self> {
+        match ptr.is_null() {
+            true => Some(Self::null()),
+            false => compress::<B>(ptr as *const u8).map(|encoded| Self {
+                encoded: Some(encoded),
+                _traits: marker::PhantomData,
+            }),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns whether pointer is null.
+    pub const fn is_null(&self) -> bool {
+        self.encoded.is_none()
+    }
+
+    #[inline(always)]
+    ///Returns offset from base address, unless pointer is null.
+    pub fn offset(&self) -> Option<u32> {
+        self.encoded.map(|encoded| encoded.get() - 1)
+    }
+
+    #[inline]
+    ///Gets underlying raw pointer, decompressing it.
+    pub fn get(&self) -> *mut T {
+        match self.encoded {
+            Some(encoded) => decompress::<B>(encoded) as *mut T,
+            None => ptr::null_mut(),
+        }
+    }
+
+    #[inline]
+    ///Gets reference to pointed value, if pointer is not null.
+    ///
+    ///User must guarantee that pointer points to valid value.
+    pub unsafe fn as_ref(&self) -> Option<&T> {
+        self.get().as_ref()
+    }
+
+    #[inline]
+    ///Gets mutable reference to pointed value, if pointer is not null.
+    ///
+    ///User must guarantee that pointer points to valid value, which is not aliased.
+    pub unsafe fn as_mut(&mut self) -> Option<&mut T> {
+        self.get().as_mut()
+    }
+}
+
+impl<T, B: PtrBase> Clone for CompressedPtr<T, B> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, B: PtrBase> Copy for CompressedPtr<T, B> {}
+
+impl<T, B: PtrBase> PartialEq for CompressedPtr<T, B> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.encoded == other.encoded
+    }
+}
+
+impl<T, B: PtrBase> Eq for CompressedPtr<T, B> {}
+
+impl<T, B: PtrBase> Default for CompressedPtr<T, B> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl<T, B: PtrBase> fmt::Pointer for CompressedPtr<T, B> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.get(), fmt)
+    }
+}
+
+impl<T, B: PtrBase> fmt::Debug for CompressedPtr<T, B> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.get(), fmt)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T, B: PtrBase> defmt::Format for CompressedPtr<T, B> {
+    #[inline(always)]
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(fmt, "{=str}@{=usize:#x}", core::any::type_name::<T>(), self.get() as *const u8 as usize)
+    }
+}
+
+#[repr(transparent)]
+///Smart pointer, that owns object via compressed pointer.
+///
+///On `Drop` it disposes of decompressed pointer with provided deleter.
+///
+///`Option` of it has the same size as the pointer itself.
+pub struct CompressedUnique<T, B: PtrBase, D: Deleter> {
+    encoded: NonZeroU32,
+    _traits: marker::PhantomData<(T, B, D)>,
+}
+
+impl<T, B: PtrBase, D: Deleter> CompressedUnique<T, B, D> {
+    #[inline]
+    ///Creates new instance from raw pointer.
+    ///
+    ///# Panics
+    ///
+    ///- If pointer is null, below base address or its offset doesn't fit into 32 bits.
+    pub unsafe fn new(ptr: *mut T) -> Self {
+        assert!(!ptr.is_null());
+
+        Self::from_ptr(ptr).expect("Pointer is outside of region")
+    }
+
+    #[inline]
+    ///Creates instance from raw pointer, checking if pointer is null.
+    ///
+    ///Returns `None` if pointer is null, below base address or its offset doesn't fit into 32 bits.
+    pub unsafe fn from_ptr(ptr: *mut T) -> Option<Self> {
+        match ptr.is_null() {
+            true => None,
+            false => compress::<B>(ptr as *const u8).map(|encoded| Self {
+                encoded,
+                _traits: marker::PhantomData,
+            }),
+        }
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer, decompressing it.
+    pub fn get(&self) -> *mut T {
+        decompress::<B>(self.encoded) as *mut T
+    }
+
+    #[inline(always)]
+    ///Returns compressed pointer, without giving up ownership.
+    pub fn as_compressed(&self) -> CompressedPtr<T, B> {
+        CompressedPtr {
+            encoded: Some(self.encoded),
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Releases the ownership and returns raw pointer, without dropping it.
+    pub fn release(self) -> ptr::NonNull<T> {
+        let result = self.get();
+        core::mem::forget(self);
+        unsafe {
+            ptr::NonNull::new_unchecked(result)
+        }
+    }
+}
+
+impl<T, B: PtrBase, D: Deleter> Drop for CompressedUnique<T, B, D> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            D::delete_with_layout::<T>(self.get(), core::alloc::Layout::new::<T>())
+        }
+    }
+}
+
+impl<T, B: PtrBase, D: Deleter> core::ops::Deref for CompressedUnique<T, B, D> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            &*self.get()
+        }
+    }
+}
+
+impl<T, B: PtrBase, D: Deleter> core::ops::DerefMut for CompressedUnique<T, B, D> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe {
+            &mut *self.get()
+        }
+    }
+}
+
+impl<T, B: PtrBase, D: Deleter> fmt::Pointer for CompressedUnique<T, B, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.get(), fmt)
+    }
+}
+
+impl<T: fmt::Debug, B: PtrBase, D: Deleter> fmt::Debug for CompressedUnique<T, B, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, fmt)
+    }
+}
+
+unsafe impl<T: Send, B: PtrBase, D: Deleter> Send for CompressedUnique<T, B, D> {}
+
+unsafe impl<T: Sync, B: PtrBase, D: Deleter> Sync for CompressedUnique<T, B, D> {}
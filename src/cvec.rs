@@ -0,0 +1,366 @@
+//!Owned buffer of elements, described by pointer, length and capacity.
+
+use core::{mem, fmt, marker};
+
+use crate::Deleter;
+
+///Describes how to de-allocate buffer.
+///
+///Any [Deleter](../trait.Deleter.html) can be used as buffer deleter, in which case its
+///`delete_slice` receives initialized elements.
+pub trait BufferDeleter {
+    ///This function is called on `Drop` with buffer's pointer, length and capacity.
+    unsafe fn delete<T>(ptr: *mut T, len: usize, capacity: usize);
+}
+
+impl<D: Deleter> BufferDeleter for D {
+    #[inline(always)]
+    unsafe fn delete<T>(ptr: *mut T, len: usize, _: usize) {
+        D::delete_slice::<T>(ptr, len)
+    }
+}
+
+///Describes how to grow buffer, which is disposed of with the same deleter.
+///
+///Implement it alongside `BufferDeleter` with C library's `realloc` and `free`, to append to
+///buffers on Rust side, which are ultimately freed by C code.
+pub trait Realloc: BufferDeleter {
+    ///Re-allocates buffer of `capacity` elements to fit `new_capacity` elements, preserving its content.
+    ///
+    ///Pointer is null, if buffer is not allocated yet.
+    ///
+    ///Returns null on failure, in which case original buffer must be left intact.
+    unsafe fn realloc<T>(ptr: *mut T, capacity: usize, new_capacity: usize) -> *mut T;
+}
+
+#[derive(Default)]
+///Buffer deleter, which re-creates `Vec` from pointer, length and capacity.
+///
+///Buffer is grown using global allocator, in the same way as `Vec`.
+pub struct VecDeleter;
+
+#[cfg(feature = "alloc")]
+impl BufferDeleter for VecDeleter {
+    #[inline(always)]
+    unsafe fn delete<T>(ptr: *mut T, len: usize, capacity: usize) {
+        drop(alloc::vec::Vec::from_raw_parts(ptr, len, capacity));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Realloc for VecDeleter {
+    unsafe fn realloc<T>(ptr: *mut T, capacity: usize, new_capacity: usize) -> *mut T {
+        let layout = match core::alloc::Layout::array::<T>(new_capacity) {
+            Ok(layout) => layout,
+            Err(_) => return core::ptr::null_mut(),
+        };
+
+        //Zero sized values are never allocated
+        if layout.size() == 0 {
+            return core::ptr::NonNull::dangling().as_ptr();
+        }
+
+        match ptr.is_null() || capacity == 0 {
+            true => alloc::alloc::alloc(layout) as *mut T,
+            false => {
+                let old_layout = core::alloc::Layout::array::<T>(capacity).expect("capacity overflow");
+                alloc::alloc::realloc(ptr as *mut u8, old_layout, layout.size()) as *mut T
+            }
+        }
+    }
+}
+
+///Buffer, that owns pointer, length and capacity.
+///
+///Common representation of `(ptr, len)` buffers, returned by C libraries.
+///
+///On `Drop` it disposes of buffer with provided deleter, passing stored length and capacity.
+///Deleter is not invoked for null pointer, which is treated as empty buffer.
+///
+///```rust
+///use smart_ptr::CVec;
+///
+///let mut storage = [1u8, 2, 3];
+///let mut buffer = unsafe { CVec::<u8, ()>::from_raw(storage.as_mut_ptr(), storage.len()) };
+///buffer[0] = 0;
+///assert_eq!(buffer.len(), 3);
+///assert_eq!(*buffer, [0, 2, 3]);
+///```
+pub struct CVec<T, D: BufferDeleter> {
+    ptr: *mut T,
+    len: usize,
+    capacity: usize,
+    _traits: marker::PhantomData<(T, D)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> CVec<T, VecDeleter> {
+    #[inline]
+    ///Creates new instance from `Vec`, preserving its capacity.
+    pub fn from_vec(vec: alloc::vec::Vec<T>) -> Self {
+        let mut vec = mem::ManuallyDrop::new(vec);
+        unsafe {
+            Self::from_raw_parts(vec.as_mut_ptr(), vec.len(), vec.capacity())
+        }
+    }
+}
+
+impl<T, D: BufferDeleter> CVec<T, D> {
+    #[inline(always)]
+    ///Creates new empty buffer, without allocating.
+    pub const fn new() -> Self {
+        Self {
+            ptr: core::ptr::null_mut(),
+            len: 0,
+            capacity: 0,
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Creates new instance from raw pointer and number of initialized elements.
+    ///
+    ///Capacity is assumed to be equal to length.
+    ///
+    ///User must guarantee that pointer is either null or points to `len` initialized elements.
+    pub unsafe fn from_raw(ptr: *mut T, len: usize) -> Self {
+        Self::from_raw_parts(ptr, len, len)
+    }
+
+    #[inline]
+    ///Creates new instance from raw pointer, number of initialized elements and capacity.
+    ///
+    ///User must guarantee that pointer is either null or points to `len` initialized elements.
+    ///
+    ///# Panics
+    ///
+    ///- If pointer is null while length is not zero.
+    pub unsafe fn from_raw_parts(ptr: *mut T, len: usize, capacity: usize) -> Self {
+        assert!(!ptr.is_null() || len == 0);
+        debug_assert!(len <= capacity);
+
+        Self {
+            ptr,
+            len,
+            capacity,
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    ///Returns whether buffer has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    ///Returns buffer's capacity.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer, which can be null for empty buffer.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer, which can be null for empty buffer.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
+    #[inline]
+    ///Gets slice of elements.
+    pub fn as_slice(&self) -> &[T] {
+        match self.ptr.is_null() {
+            true => &[],
+            false => unsafe {
+                core::slice::from_raw_parts(self.ptr, self.len)
+            },
+        }
+    }
+
+    #[inline]
+    ///Gets mutable slice of elements.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match self.ptr.is_null() {
+            true => &mut [],
+            false => unsafe {
+                core::slice::from_raw_parts_mut(self.ptr, self.len)
+            },
+        }
+    }
+
+    #[inline]
+    ///Releases the ownership and returns raw pointer, length and capacity, without dropping it.
+    pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
+        let result = (self.ptr, self.len, self.capacity);
+        mem::forget(self);
+        result
+    }
+}
+
+///Growable buffer, which is re-allocated via [Realloc](trait.Realloc.html).
+///
+///It is [CVec](struct.CVec.html), whose deleter knows how to grow buffer.
+///
+///```rust
+///use smart_ptr::cvec::{BufferDeleter, Realloc, ReallocBuffer};
+///use std::alloc::{Layout, alloc, dealloc, realloc};
+///
+/////Mimics C's `realloc` and `free`
+///struct Malloc;
+///
+///impl BufferDeleter for Malloc {
+///    unsafe fn delete<T>(ptr: *mut T, _len: usize, capacity: usize) {
+///        dealloc(ptr as *mut u8, Layout::array::<T>(capacity).unwrap());
+///    }
+///}
+///
+///impl Realloc for Malloc {
+///    unsafe fn realloc<T>(ptr: *mut T, capacity: usize, new_capacity: usize) -> *mut T {
+///        match ptr.is_null() {
+///            true => alloc(Layout::array::<T>(new_capacity).unwrap()) as *mut T,
+///            false => realloc(ptr as *mut u8, Layout::array::<T>(capacity).unwrap(), new_capacity * core::mem::size_of::<T>()) as *mut T,
+///        }
+///    }
+///}
+///
+///let mut buffer = ReallocBuffer::<u8, Malloc>::new();
+///buffer.push(1);
+///buffer.extend_from_slice(&[2, 3]);
+///assert_eq!(*buffer, [1, 2, 3]);
+///assert!(buffer.capacity() >= 3);
+///```
+pub type ReallocBuffer<T, D> = CVec<T, D>;
+
+impl<T, D: Realloc> CVec<T, D> {
+    ///Reserves capacity for at least `additional` more elements.
+    ///
+    ///On failure buffer is left intact.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), crate::Error> {
+        let required = self.len.checked_add(additional).ok_or(crate::Error::AllocFailed)?;
+        if required <= self.capacity {
+            return Ok(());
+        }
+
+        let capacity = core::cmp::max(core::cmp::max(self.capacity.saturating_mul(2), required), 4);
+        let ptr = unsafe {
+            D::realloc::<T>(self.ptr, self.capacity, capacity)
+        };
+
+        match ptr.is_null() {
+            true => Err(crate::Error::AllocFailed),
+            false => {
+                self.ptr = ptr;
+                self.capacity = capacity;
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    ///Reserves capacity for at least `additional` more elements.
+    ///
+    ///# Panics
+    ///
+    ///- If re-allocation fails.
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(error) = self.try_reserve(additional) {
+            panic!("{}", error);
+        }
+    }
+
+    #[inline]
+    ///Appends element to the end of buffer, growing it if necessary.
+    ///
+    ///# Panics
+    ///
+    ///- If re-allocation fails.
+    pub fn push(&mut self, val: T) {
+        self.reserve(1);
+        unsafe {
+            self.ptr.add(self.len).write(val);
+        }
+        self.len += 1;
+    }
+
+    ///Appends clones of all elements of `val`, growing buffer if necessary.
+    ///
+    ///# Panics
+    ///
+    ///- If re-allocation fails.
+    pub fn extend_from_slice(&mut self, val: &[T]) where T: Clone {
+        self.reserve(val.len());
+        for elem in val {
+            //Length is updated right away, in case of panic within `clone`
+            unsafe {
+                self.ptr.add(self.len).write(elem.clone());
+            }
+            self.len += 1;
+        }
+    }
+}
+
+impl<T, D: BufferDeleter> Default for CVec<T, D> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, D: BufferDeleter> Drop for CVec<T, D> {
+    #[inline]
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe {
+                D::delete::<T>(self.ptr, self.len, self.capacity)
+            }
+        }
+    }
+}
+
+impl<T, D: BufferDeleter> core::ops::Deref for CVec<T, D> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, D: BufferDeleter> core::ops::DerefMut for CVec<T, D> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: fmt::Debug, D: BufferDeleter> fmt::Debug for CVec<T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), fmt)
+    }
+}
+
+impl<T: PartialEq, D: BufferDeleter> PartialEq<Self> for CVec<T, D> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, D: BufferDeleter> Eq for CVec<T, D> {
+}
+
+unsafe impl<T: Send, D: BufferDeleter> Send for CVec<T, D> {}
+
+unsafe impl<T: Sync, D: BufferDeleter> Sync for CVec<T, D> {}
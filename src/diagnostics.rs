@@ -0,0 +1,142 @@
+//!Per-type counters of live pointers, enabled by `diagnostics` feature.
+//!
+//!Every `Unique` increments counter of its pointee type on creation and decrements it once it is
+//!released or deleted.
+//!
+//!Counters are updated without lock, as each type is assigned its own atomic counter. Types are
+//!identified by their name and at most 1024 distinct types are tracked.
+//!
+//!```rust
+//!use smart_ptr::Unique;
+//!
+//!struct Session;
+//!
+//!let mut session = Session;
+//!let ptr = Unique::<Session, ()>::from(&mut session);
+//!assert_eq!(smart_ptr::live_count::<Session>(), Some(1));
+//!drop(ptr);
+//!assert_eq!(smart_ptr::live_count::<Session>(), Some(0));
+//!```
+
+use core::{ptr, slice, str};
+use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+//Maximum number of distinct types, that can be tracked.
+const CAPACITY: usize = 1024;
+
+struct Counter {
+    //Hash of type name, zero if slot is not claimed yet.
+    hash: AtomicUsize,
+    //Name is published right after slot is claimed, hence it is null only for a moment.
+    name: AtomicPtr<u8>,
+    name_len: AtomicUsize,
+    count: AtomicUsize,
+}
+
+impl Counter {
+    const fn new() -> Self {
+        Self {
+            hash: AtomicUsize::new(0),
+            name: AtomicPtr::new(ptr::null_mut()),
+            name_len: AtomicUsize::new(0),
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        let name = self.name.load(Ordering::Acquire);
+        match name.is_null() {
+            true => None,
+            false => unsafe {
+                Some(str::from_utf8_unchecked(slice::from_raw_parts(name, self.name_len.load(Ordering::Relaxed))))
+            }
+        }
+    }
+}
+
+//Open addressing table of counters, where slot is claimed once by the first pointer of the type
+//and never released, so that lookup requires no lock.
+static COUNTERS: [Counter; CAPACITY] = [const { Counter::new() }; CAPACITY];
+
+enum Lookup {
+    Found(&'static AtomicUsize),
+    //Type has no counter, as it is never claimed.
+    Vacant,
+    //Type has no counter, as table is full.
+    Full,
+}
+
+//Looks up counter of `T`, claiming new slot if `is_claim` is set.
+//
+//Hashes of distinct types may collide, therefore name of type is compared as well.
+fn counter<T: ?Sized>(is_claim: bool) -> Lookup {
+    let name = core::any::type_name::<T>();
+    let hash = crate::type_hash::<T>();
+    let mut idx = hash % CAPACITY;
+
+    for _ in 0..CAPACITY {
+        let counter = &COUNTERS[idx];
+        let mut current = counter.hash.load(Ordering::Acquire);
+        if current == 0 {
+            if !is_claim {
+                return Lookup::Vacant;
+            }
+
+            match counter.hash.compare_exchange(0, hash, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => {
+                    counter.name_len.store(name.len(), Ordering::Relaxed);
+                    counter.name.store(name.as_ptr() as *mut u8, Ordering::Release);
+                    return Lookup::Found(&counter.count);
+                },
+                Err(actual) => current = actual,
+            }
+        }
+
+        if current == hash {
+            let current_name = loop {
+                match counter.name() {
+                    Some(name) => break name,
+                    None => core::hint::spin_loop(),
+                }
+            };
+
+            if current_name == name {
+                return Lookup::Found(&counter.count);
+            }
+        }
+        idx = (idx + 1) % CAPACITY;
+    }
+
+    Lookup::Full
+}
+
+pub(crate) fn increment<T: ?Sized>() {
+    if let Lookup::Found(count) = counter::<T>(true) {
+        count.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+pub(crate) fn decrement<T: ?Sized>() {
+    if let Lookup::Found(count) = counter::<T>(false) {
+        count.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+///Returns number of live pointers to `T`, which are neither released nor deleted yet.
+///
+///Returns `None` if `T` is not tracked, as counters of 1024 distinct types are already in use.
+pub fn live_count<T: ?Sized>() -> Option<usize> {
+    match counter::<T>(false) {
+        Lookup::Found(count) => Some(count.load(Ordering::Relaxed)),
+        Lookup::Vacant => Some(0),
+        Lookup::Full => None,
+    }
+}
+
+///Returns number of live pointers for each type, which has any.
+pub fn live_counts() -> alloc::vec::Vec<(&'static str, usize)> {
+    COUNTERS.iter().filter_map(|counter| match counter.count.load(Ordering::Relaxed) {
+        0 => None,
+        count => counter.name().map(|name| (name, count)),
+    }).collect()
+}
@@ -0,0 +1,74 @@
+//!Errors of fallible constructors
+
+use core::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+///Error, indicating that pointer is null.
+pub struct NullPtrError;
+
+impl fmt::Display for NullPtrError {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("Pointer is null")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for NullPtrError {
+    #[inline(always)]
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(fmt, "Pointer is null")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NullPtrError {
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+///Error of pointer validation, returned by fallible constructors.
+pub enum Error {
+    ///Pointer is null.
+    Null,
+    ///Pointer is not aligned as required by its type.
+    Misaligned {
+        ///Required alignment.
+        required: usize,
+        ///Address of the pointer.
+        found: usize,
+    },
+    ///Allocator failed to provide memory.
+    AllocFailed,
+}
+
+impl From<NullPtrError> for Error {
+    #[inline(always)]
+    fn from(_: NullPtrError) -> Self {
+        Error::Null
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Null => fmt::Display::fmt(&NullPtrError, fmt),
+            Error::Misaligned { required, found } => fmt.write_fmt(format_args!("Pointer 0x{:x} is not aligned to {} bytes", found, required)),
+            Error::AllocFailed => fmt.write_str("Memory allocation failed"),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Error {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        match self {
+            Error::Null => defmt::Format::format(&NullPtrError, fmt),
+            Error::Misaligned { required, found } => defmt::write!(fmt, "Pointer {=usize:#x} is not aligned to {=usize} bytes", found, required),
+            Error::AllocFailed => defmt::write!(fmt, "Memory allocation failed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+}
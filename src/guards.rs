@@ -0,0 +1,41 @@
+//!Shadow set of disposed pointers, enabled by `debug-guards` feature.
+//!
+//!Once pointer is released or deleted, its owner is gone, therefore poisoned value cannot be
+//!observed by another owner of the same pointer. Instead most recently disposed pointers are
+//!remembered here, until pointer is owned anew.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+//Number of most recently disposed pointers, that are remembered.
+const CAPACITY: usize = 64;
+
+static DISPOSED: [AtomicUsize; CAPACITY] = [const { AtomicUsize::new(0) }; CAPACITY];
+static CURSOR: AtomicUsize = AtomicUsize::new(0);
+
+//Pointers of different types may share address (e.g. struct and its first field), hence owner's
+//type is mixed into the key.
+fn key<P: ?Sized>(address: usize) -> usize {
+    //Zero marks empty slot
+    (address ^ crate::type_hash::<P>()).max(1)
+}
+
+//Remembers pointer of owner `P` as disposed.
+pub(crate) fn dispose<P: ?Sized>(address: usize) {
+    let idx = CURSOR.fetch_add(1, Ordering::Relaxed) % CAPACITY;
+    DISPOSED[idx].store(key::<P>(address), Ordering::Release);
+}
+
+//Forgets disposal of pointer, once it is owned by `P` again.
+pub(crate) fn revive<P: ?Sized>(address: usize) {
+    let key = key::<P>(address);
+    for slot in DISPOSED.iter() {
+        if slot.load(Ordering::Acquire) == key {
+            let _ = slot.compare_exchange(key, 0, Ordering::AcqRel, Ordering::Relaxed);
+        }
+    }
+}
+
+pub(crate) fn is_disposed<P: ?Sized>(address: usize) -> bool {
+    let key = key::<P>(address);
+    DISPOSED.iter().any(|slot| slot.load(Ordering::Acquire) == key)
+}
@@ -0,0 +1,295 @@
+//!Hazard pointer reclamation implementation
+//!
+//!Allows to safely dispose of pointers, removed from `AtomicUnique`, while other threads may
+//!still read them.
+//!
+//!```rust
+//!use smart_ptr::hazard::HazardDomain;
+//!use smart_ptr::{AtomicUnique, Unique};
+//!
+//!static mut FIRST: u32 = 1;
+//!static mut SECOND: u32 = 2;
+//!
+//!let domain = HazardDomain::<2, 4>::new();
+//!let atomic = AtomicUnique::<u32, ()>::new(Some(unsafe { Unique::new(core::ptr::addr_of_mut!(FIRST)) }));
+//!
+//!let mut guard = domain.guard().expect("to have free hazard slot");
+//!assert_eq!(unsafe { guard.protect(&atomic) }, Some(&1));
+//!
+//!let old = atomic.swap(Some(unsafe { Unique::new(core::ptr::addr_of_mut!(SECOND)) })).expect("to have value");
+//!//Pointer is protected by guard, therefore its deletion is deferred
+//!domain.retire(old);
+//!assert_eq!(domain.retired_len(), 1);
+//!
+//!drop(guard);
+//!domain.reclaim();
+//!assert_eq!(domain.retired_len(), 0);
+//!```
+
+use core::{fmt, hint, mem, ptr};
+use core::cell::UnsafeCell;
+use core::sync::atomic::{self, AtomicBool, AtomicPtr, Ordering};
+
+use crate::{Deleter, Unique, AtomicUnique};
+use crate::utils::CallOnDrop;
+
+struct Hazard {
+    is_used: AtomicBool,
+    ptr: AtomicPtr<()>,
+}
+
+impl Hazard {
+    #[allow(clippy::declare_interior_mutable_const)]
+    //Only used to initialize array of hazards
+    const NEW: Self = Self {
+        is_used: AtomicBool::new(false),
+        ptr: AtomicPtr::new(ptr::null_mut()),
+    };
+}
+
+#[derive(Clone, Copy)]
+struct Retired {
+    ptr: *mut (),
+    delete: unsafe fn(*mut ()),
+}
+
+unsafe fn delete_erased<T, D: Deleter>(ptr: *mut ()) {
+    D::delete_with_layout::<T>(ptr as *mut T, core::alloc::Layout::new::<T>())
+}
+
+///Hazard pointer domain with `H` hazard slots and capacity of `R` retired pointers.
+///
+///Retired pointers are passed to their `Deleter` only when there is no hazard on them.
+///
+///`R` must be greater than `H`, to guarantee that retiring can always make progress.
+///
+///Domain doesn't allocate, therefore it can be used in `static` context.
+pub struct HazardDomain<const H: usize, const R: usize> {
+    hazards: [Hazard; H],
+    is_retiring: AtomicBool,
+    retired: UnsafeCell<[Option<Retired>; R]>,
+}
+
+impl<const H: usize, const R: usize> HazardDomain<H, R> {
+    ///Creates new domain.
+    ///
+    ///# Panics
+    ///
+    ///- If `R` is not greater than `H`
+    pub const fn new() -> Self {
+        assert!(R > H, "Retired capacity must be greater than number of hazards");
+
+        Self {
+            hazards: [Hazard::NEW; H],
+            is_retiring: AtomicBool::new(false),
+            retired: UnsafeCell::new([None; R]),
+        }
+    }
+
+    ///Acquires free hazard slot.
+    ///
+    ///Returns `None` if all slots are in use.
+    pub fn guard(&self) -> Option<Guard<'_>> {
+        for hazard in self.hazards.iter() {
+            if hazard.is_used.compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed).is_ok() {
+                return Some(Guard {
+                    hazard,
+                });
+            }
+        }
+
+        None
+    }
+
+    ///Retires pointer, deferring its deletion until there is no hazard on it.
+    ///
+    ///Zero sized values are rejected at compile time, as they share address, therefore single
+    ///hazard would protect every one of them.
+    ///
+    ///```compile_fail
+    ///use smart_ptr::hazard::HazardDomain;
+    ///use smart_ptr::Unique;
+    ///
+    ///let domain = HazardDomain::<1, 2>::new();
+    ///domain.retire(Unique::<(), ()>::from(Box::leak(Box::new(()))));
+    ///```
+    ///
+    ///Deleters are invoked after lock over retired pointers is released, so they may retire
+    ///pointers as well. If deleter panics, the rest of reclaimed pointers is leaked.
+    pub fn retire<T: Send + 'static, D: Deleter + 'static>(&self, ptr: Unique<'static, T, D>) {
+        const {
+            assert!(mem::size_of::<T>() != 0, "Zero sized values cannot be retired");
+        }
+
+        let retired = Retired {
+            ptr: ptr.release().as_ptr() as *mut (),
+            delete: delete_erased::<T, D>,
+        };
+
+        let mut reclaimed = [None; R];
+        {
+            let _lock = self.lock();
+            let list = unsafe {
+                &mut *self.retired.get()
+            };
+            let mut slot = list.iter_mut().find(|entry| entry.is_none());
+            if slot.is_none() {
+                self.scan(list, &mut reclaimed);
+                slot = list.iter_mut().find(|entry| entry.is_none());
+            }
+
+            match slot {
+                Some(slot) => *slot = Some(retired),
+                //Cannot happen as long as there are more retired slots than hazards, since
+                //retired values have distinct addresses.
+                None => unreachable!(),
+            }
+        }
+
+        Self::delete(reclaimed);
+    }
+
+    ///Deletes all retired pointers, which are not protected by hazards.
+    ///
+    ///Deleters are invoked after lock over retired pointers is released, so they may retire
+    ///pointers as well. If deleter panics, the rest of reclaimed pointers is leaked.
+    pub fn reclaim(&self) {
+        let mut reclaimed = [None; R];
+        {
+            let _lock = self.lock();
+            self.scan(unsafe {
+                &mut *self.retired.get()
+            }, &mut reclaimed);
+        }
+
+        Self::delete(reclaimed);
+    }
+
+    ///Returns number of retired pointers, which are pending deletion.
+    pub fn retired_len(&self) -> usize {
+        let _lock = self.lock();
+        unsafe {
+            (*self.retired.get()).iter().filter(|entry| entry.is_some()).count()
+        }
+    }
+
+    //Moves retired pointers, which are not protected by hazards, from `list` into `reclaimed`.
+    fn scan(&self, list: &mut [Option<Retired>; R], reclaimed: &mut [Option<Retired>; R]) {
+        //Pairs with fence in `Guard::protect`, so that either hazard is visible here or
+        //reader observes pointer being removed.
+        atomic::fence(Ordering::SeqCst);
+
+        for (entry, reclaimed) in list.iter_mut().zip(reclaimed.iter_mut()) {
+            if let Some(retired) = entry {
+                if !self.is_protected(retired.ptr) {
+                    *reclaimed = entry.take();
+                }
+            }
+        }
+    }
+
+    //Deletes reclaimed pointers, which must be no longer accessible via retired list.
+    fn delete(reclaimed: [Option<Retired>; R]) {
+        for retired in reclaimed.iter().flatten() {
+            unsafe {
+                (retired.delete)(retired.ptr);
+            }
+        }
+    }
+
+    #[inline]
+    fn is_protected(&self, ptr: *mut ()) -> bool {
+        self.hazards.iter().any(|hazard| hazard.ptr.load(Ordering::SeqCst) == ptr)
+    }
+
+    #[inline]
+    //Acquires lock over retired list, which is released on `Drop` of returned guard, even if deleter panics.
+    fn lock(&self) -> CallOnDrop<impl FnOnce() + '_> {
+        while self.is_retiring.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed).is_err() {
+            hint::spin_loop();
+        }
+
+        CallOnDrop::new(move || self.is_retiring.store(false, Ordering::Release))
+    }
+}
+
+impl<const H: usize, const R: usize> Default for HazardDomain<H, R> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const H: usize, const R: usize> Drop for HazardDomain<H, R> {
+    fn drop(&mut self) {
+        for retired in self.retired.get_mut().iter_mut().filter_map(Option::take) {
+            unsafe {
+                (retired.delete)(retired.ptr);
+            }
+        }
+    }
+}
+
+impl<const H: usize, const R: usize> fmt::Debug for HazardDomain<H, R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("HazardDomain").field("retired", &self.retired_len()).finish()
+    }
+}
+
+unsafe impl<const H: usize, const R: usize> Send for HazardDomain<H, R> {}
+
+unsafe impl<const H: usize, const R: usize> Sync for HazardDomain<H, R> {}
+
+///Hazard slot, which protects single pointer from deletion.
+///
+///Slot is returned to domain on `Drop`.
+pub struct Guard<'d> {
+    hazard: &'d Hazard,
+}
+
+impl<'d> Guard<'d> {
+    ///Loads pointer from `src`, protecting it from deletion for as long as guard is not reset.
+    ///
+    ///Returns `None` if `src` is empty.
+    ///
+    ///# Safety
+    ///
+    ///All pointers, removed from `src`, must be disposed of only via `retire` of guard's domain.
+    pub unsafe fn protect<'g, T: Sync, D: Deleter>(&'g mut self, src: &AtomicUnique<'_, T, D>) -> Option<&'g T> {
+        let mut ptr = src.as_ptr();
+        loop {
+            self.hazard.ptr.store(ptr as *mut (), Ordering::SeqCst);
+            //Hazard must be visible to reclaimer before pointer is validated.
+            atomic::fence(Ordering::SeqCst);
+            //Pointer could be replaced before hazard was published, so validate it.
+            let actual = src.as_ptr();
+            if actual == ptr {
+                break;
+            }
+            ptr = actual;
+        }
+
+        ptr.as_ref()
+    }
+
+    #[inline]
+    ///Removes protection from previously protected pointer.
+    pub fn reset(&mut self) {
+        self.hazard.ptr.store(ptr::null_mut(), Ordering::Release);
+    }
+}
+
+impl<'d> Drop for Guard<'d> {
+    #[inline]
+    fn drop(&mut self) {
+        self.reset();
+        self.hazard.is_used.store(false, Ordering::Release);
+    }
+}
+
+impl<'d> fmt::Debug for Guard<'d> {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.hazard.ptr.load(Ordering::Relaxed), fmt)
+    }
+}
@@ -0,0 +1,327 @@
+//!Intrusive reference counting pointer implementation
+
+use core::{mem, fmt, ptr, marker};
+use core::sync::atomic::{self, AtomicUsize, Ordering};
+
+use crate::Deleter;
+
+///Describes object, which embeds its own reference count.
+///
+///# Safety
+///
+///Implementation must guarantee that `dec` returns `true` only once, when last reference is gone.
+pub unsafe trait RefCounted {
+    ///Increments reference count.
+    fn inc(&self);
+    ///Decrements reference count, returning `true` if it reached zero.
+    fn dec(&self) -> bool;
+}
+
+///Atomic reference counter, which can be embedded into object.
+///
+///Starts with count of `1`, which corresponds to the reference owned by the creator.
+pub struct RefCounter {
+    count: AtomicUsize,
+}
+
+impl RefCounter {
+    #[inline(always)]
+    ///Creates new counter with single reference.
+    pub const fn new() -> Self {
+        Self {
+            count: AtomicUsize::new(1),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns current number of references.
+    pub fn get(&self) -> usize {
+        self.count.load(Ordering::Acquire)
+    }
+}
+
+impl Default for RefCounter {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl RefCounted for RefCounter {
+    #[inline]
+    fn inc(&self) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    fn dec(&self) -> bool {
+        match self.count.fetch_sub(1, Ordering::Release) {
+            1 => {
+                atomic::fence(Ordering::Acquire);
+                true
+            },
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for RefCounter {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.get(), fmt)
+    }
+}
+
+#[cfg(feature = "alloc")]
+///Alias to `IntrusiveShared` with `GlobalDeleter` as second type parameter
+pub type GlobalShared<T> = IntrusiveShared<T, crate::GlobalDeleter>;
+
+#[cfg(feature = "alloc")]
+impl<T: RefCounted> GlobalShared<T> {
+    #[inline]
+    ///Creates new instance using global allocator.
+    ///
+    ///Value's reference count is expected to be `1`.
+    pub fn boxed(val: T) -> Self {
+        unsafe {
+            Self::from_ptr_unchecked(crate::boxed_raw(val))
+        }
+    }
+
+    #[inline]
+    ///Creates new pinned instance using global allocator.
+    ///
+    ///Value's reference count is expected to be `1`.
+    pub fn pin(val: T) -> core::pin::Pin<Self> {
+        Self::boxed(val).into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + RefCounted> From<GlobalShared<T>> for core::pin::Pin<GlobalShared<T>> {
+    #[inline(always)]
+    fn from(ptr: GlobalShared<T>) -> Self {
+        //Shared pointer only provides shared access to the value, which lives in its own allocation
+        //until last reference is gone, hence value cannot be moved out of it
+        unsafe {
+            ptr.into_pin_unchecked()
+        }
+    }
+}
+
+#[repr(transparent)]
+///Shared pointer, that relies on object's embedded reference count.
+///
+///On `Clone` it increments reference count, while on `Drop` it decrements it
+///and disposes of pointer with provided deleter once count reaches zero.
+///
+///Pointer owns single reference to the object.
+///
+///# Safety
+///
+///If you use [Deleter](../trait.Deleter.html) that relies on type information, you must guarantee
+///that object was created using the same type as pointer, which points to it.
+pub struct IntrusiveShared<T: ?Sized + RefCounted, D: Deleter> {
+    inner: ptr::NonNull<T>,
+    _traits: marker::PhantomData<D>,
+}
+
+impl<T: ?Sized + RefCounted, D: Deleter> IntrusiveShared<T, D> {
+    #[inline]
+    ///Creates new instance from raw pointer, taking ownership of existing reference.
+    ///
+    ///# Panics
+    ///
+    ///- If pointer is null
+    pub unsafe fn new(ptr: *mut T) -> Self {
+        assert!(!ptr.is_null());
+
+        Self::from_ptr_unchecked(ptr)
+    }
+
+    #[inline]
+    ///Creates instance from raw pointer, taking ownership of existing reference.
+    ///
+    ///Returns `None` if pointer is null.
+    pub unsafe fn from_ptr(ptr: *mut T) -> Option<Self> {
+        match ptr.is_null() {
+            true => None,
+            false => Some(Self::from_ptr_unchecked(ptr)),
+        }
+    }
+
+    #[inline]
+    ///Creates instance from raw pointer, without checking if pointer is null.
+    ///
+    ///User must ensure that pointer is non-null
+    pub unsafe fn from_ptr_unchecked(ptr: *mut T) -> Self {
+        Self {
+            inner: ptr::NonNull::new_unchecked(ptr),
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Creates instance from borrowed raw pointer, acquiring new reference.
+    ///
+    ///Returns `None` if pointer is null.
+    pub unsafe fn from_ptr_borrowed(ptr: *mut T) -> Option<Self> {
+        match ptr.is_null() {
+            true => None,
+            false => {
+                (*ptr).inc();
+                Some(Self::from_ptr_unchecked(ptr))
+            }
+        }
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer, without affecting reference count.
+    pub fn get(&self) -> *mut T {
+        self.inner.as_ptr()
+    }
+
+    #[inline(always)]
+    ///Gets reference to underlying data.
+    pub fn as_ref(&self) -> &T {
+        self
+    }
+
+    #[inline(always)]
+    ///Returns whether both pointers point to the same object.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        ptr::eq(self.inner.as_ptr(), other.inner.as_ptr())
+    }
+
+    #[inline(always)]
+    ///Swaps underlying pointers between instances
+    pub fn swap(&mut self, other: &mut Self) {
+        mem::swap(&mut self.inner, &mut other.inner);
+    }
+
+    #[inline]
+    ///Releases the ownership and returns raw pointer, without decrementing reference count.
+    pub fn release(self) -> ptr::NonNull<T> {
+        let result = self.inner;
+        mem::forget(self);
+        result
+    }
+
+    #[inline(always)]
+    ///Converts into pinned pointer.
+    ///
+    ///User must guarantee that value will not be moved out of its memory until it is disposed of
+    ///by deleter, including via other references to the same object.
+    pub unsafe fn into_pin_unchecked(self) -> core::pin::Pin<Self> {
+        core::pin::Pin::new_unchecked(self)
+    }
+}
+
+impl<T: RefCounted + crate::waker::Wake + 'static, D: Deleter + 'static> IntrusiveShared<T, D> {
+    #[inline(always)]
+    ///Converts into `RawWaker`, which owns reference to the object.
+    ///
+    ///Cloning waker increments reference count, while dropping it decrements it.
+    pub fn into_raw_waker(self) -> core::task::RawWaker {
+        crate::waker::raw_waker(self)
+    }
+
+    #[inline(always)]
+    ///Converts into `Waker`, which owns reference to the object.
+    ///
+    ///Cloning waker increments reference count, while dropping it decrements it.
+    pub fn into_waker(self) -> core::task::Waker {
+        unsafe {
+            core::task::Waker::from_raw(self.into_raw_waker())
+        }
+    }
+}
+
+impl<T: ?Sized + RefCounted, D: Deleter> Clone for IntrusiveShared<T, D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.as_ref().inc();
+
+        Self {
+            inner: self.inner,
+            _traits: marker::PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized + RefCounted, D: Deleter> Drop for IntrusiveShared<T, D> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.as_ref().dec() {
+            let ptr = self.inner.as_ptr();
+            unsafe {
+                D::delete_with_layout::<T>(ptr, core::alloc::Layout::for_value(&*ptr))
+            }
+        }
+    }
+}
+
+impl<T: ?Sized + RefCounted, D: Deleter> core::ops::Deref for IntrusiveShared<T, D> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            &*self.inner.as_ptr()
+        }
+    }
+}
+
+impl<T: ?Sized + RefCounted, D: Deleter> crate::OwnedPtr for IntrusiveShared<T, D> {
+    type Target = T;
+
+    #[inline(always)]
+    fn get(&self) -> *mut Self::Target {
+        IntrusiveShared::get(self)
+    }
+
+    #[inline(always)]
+    fn release(self) -> ptr::NonNull<Self::Target> {
+        IntrusiveShared::release(self)
+    }
+
+    #[inline(always)]
+    unsafe fn from_non_null(ptr: ptr::NonNull<Self::Target>) -> Self {
+        Self::from_ptr_unchecked(ptr.as_ptr())
+    }
+}
+
+impl<T: ?Sized + RefCounted, D: Deleter> fmt::Pointer for IntrusiveShared<T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.inner, fmt)
+    }
+}
+
+impl<T: ?Sized + RefCounted + fmt::Debug, D: Deleter> fmt::Debug for IntrusiveShared<T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_ref(), fmt)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T: ?Sized + RefCounted + defmt::Format, D: Deleter> defmt::Format for IntrusiveShared<T, D> {
+    #[inline(always)]
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::Format::format(self.as_ref(), fmt)
+    }
+}
+
+impl<T: ?Sized + RefCounted + fmt::Display, D: Deleter> fmt::Display for IntrusiveShared<T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_ref(), fmt)
+    }
+}
+
+impl<T: ?Sized + RefCounted, D: Deleter> Unpin for IntrusiveShared<T, D> {}
+
+unsafe impl<T: ?Sized + RefCounted + Send + Sync, D: Deleter> Send for IntrusiveShared<T, D> {}
+
+unsafe impl<T: ?Sized + RefCounted + Send + Sync, D: Deleter> Sync for IntrusiveShared<T, D> {}
@@ -0,0 +1,120 @@
+//!Registry of live pointers, enabled by `leak-tracking` feature.
+//!
+//!Every `Unique` is recorded on creation and removed once it is released or deleted, so whatever
+//!remains in registry on shutdown is leaked.
+//!
+//!```rust
+//!use smart_ptr::Unique;
+//!
+//!let mut value = 0u32;
+//!let ptr = Unique::<u32, ()>::from(&mut value);
+//!let address = ptr.get() as usize;
+//!core::mem::forget(ptr);
+//!
+//!let report = smart_ptr::leak_report();
+//!assert!(report.iter().any(|leak| leak.address == address && leak.type_name == "u32"));
+//!```
+
+use core::{fmt, hint};
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use alloc::vec::Vec;
+use alloc::collections::BTreeMap;
+
+#[derive(Clone)]
+///Record of live pointer.
+pub struct Leak {
+    ///Name of the pointee type.
+    pub type_name: &'static str,
+    ///Address of the pointer.
+    pub address: usize,
+    #[cfg(feature = "std")]
+    ///Backtrace of pointer creation.
+    ///
+    ///It is captured according to `RUST_BACKTRACE` environment variable.
+    pub backtrace: alloc::sync::Arc<std::backtrace::Backtrace>,
+}
+
+impl fmt::Debug for Leak {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = fmt.debug_struct("Leak");
+        out.field("type_name", &self.type_name).field("address", &format_args!("0x{:x}", self.address));
+        #[cfg(feature = "std")]
+        out.field("backtrace", &self.backtrace);
+        out.finish()
+    }
+}
+
+impl fmt::Display for Leak {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("{} at 0x{:x}", self.type_name, self.address))?;
+        #[cfg(feature = "std")]
+        if self.backtrace.status() == std::backtrace::BacktraceStatus::Captured {
+            fmt.write_fmt(format_args!("\n{}", self.backtrace))?;
+        }
+        Ok(())
+    }
+}
+
+struct Registry {
+    is_locked: AtomicBool,
+    //Multiple pointers may share address (e.g. zero sized values), hence list of records.
+    records: UnsafeCell<BTreeMap<usize, Vec<Leak>>>,
+}
+
+unsafe impl Sync for Registry {}
+
+impl Registry {
+    fn with<R, F: FnOnce(&mut BTreeMap<usize, Vec<Leak>>) -> R>(&self, cb: F) -> R {
+        while self.is_locked.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed).is_err() {
+            hint::spin_loop();
+        }
+        let _guard = crate::utils::CallOnDrop::new(|| self.is_locked.store(false, Ordering::Release));
+
+        cb(unsafe {
+            &mut *self.records.get()
+        })
+    }
+}
+
+static REGISTRY: Registry = Registry {
+    is_locked: AtomicBool::new(false),
+    records: UnsafeCell::new(BTreeMap::new()),
+};
+
+pub(crate) fn register<T: ?Sized>(ptr: *mut T) {
+    let address = ptr as *mut u8 as usize;
+    let leak = Leak {
+        type_name: core::any::type_name::<T>(),
+        address,
+        #[cfg(feature = "std")]
+        backtrace: alloc::sync::Arc::new(std::backtrace::Backtrace::capture()),
+    };
+
+    REGISTRY.with(|records| records.entry(address).or_default().push(leak));
+}
+
+pub(crate) fn unregister<T: ?Sized>(ptr: *mut T) {
+    let address = ptr as *mut u8 as usize;
+    let type_name = core::any::type_name::<T>();
+
+    REGISTRY.with(|records| {
+        //Pointer is registered anew on every conversion of its type, therefore record must match.
+        let list = records.get_mut(&address);
+        let idx = list.as_ref().and_then(|list| list.iter().rposition(|leak| leak.type_name == type_name));
+        debug_assert!(idx.is_some(), "{} at 0x{:x} is not registered", type_name, address);
+
+        if let (Some(list), Some(idx)) = (list, idx) {
+            list.remove(idx);
+            if list.is_empty() {
+                records.remove(&address);
+            }
+        }
+    });
+}
+
+///Returns list of pointers, which are neither released nor deleted yet.
+pub fn leak_report() -> Vec<Leak> {
+    REGISTRY.with(|records| records.values().flat_map(|list| list.iter().cloned()).collect())
+}
@@ -3,25 +3,224 @@
 //!## Features
 //!
 //!- `alloc` Enables usage of `alloc` crate
+//!- `std` Enables usage of `std` crate, implies `alloc`
+//!- `leak-tracking` Records every live `Unique` in global registry, available via `leak_report()`, implies `alloc`
+//!- `bytemuck` Enables byte views and casts of `Unique` to plain old data types
+//!- `defmt` Implements `defmt::Format` for pointers, delegating to the value, and for error types
+//!- `diagnostics` Maintains per type counters of live `Unique`, available via `live_count()`, implies `alloc`
+//!- `stats` Maintains counters of allocations, available via `stats()`, implies `alloc`
+//!- `test-util` Provides `MockDeleter`, which records deletions for verification in tests, implies `std`
+//!- `debug-guards` Poisons `Unique` on release and deletion, remembering recently disposed pointers, turning their subsequent usage into panic
+//!- `nightly` Enables features, that require nightly compiler, such as calling `Unique` to closure directly or verifying alignment of pointers in debug builds
 
 #![no_std]
+#![cfg_attr(feature = "nightly", feature(fn_traits, unboxed_closures, tuple_trait, layout_for_ptr))]
 #![warn(missing_docs)]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
+#![allow(clippy::style)]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 ///Describes how to de-allocate pointer.
+///
+///## Panics
+///
+///Deleter should never panic, as it is invoked on `Drop`, which often happens within FFI callbacks
+///or while unwinding, where panic either results in UB or abort.
+///
+///Use [AbortOnPanic](struct.AbortOnPanic.html) to guarantee abort, if deleter may panic.
 pub trait Deleter {
+    ///Specifies whether deleter only releases one of multiple references to the value (e.g.
+    ///decrements reference count) or doesn't own it at all (e.g. no-op deleter), therefore multiple
+    ///`Unique` may own the same pointer.
+    ///
+    ///Pointers of shared deleters are not tracked by `debug-guards`.
+    const SHARED: bool = false;
+
     ///This function is called on `Drop`
     unsafe fn delete<T: ?Sized>(ptr: *mut T);
+
+    #[inline(always)]
+    ///This function is called on `Drop` of [Unique](unique/struct.Unique.html) and other owners with layout of the value.
+    ///
+    ///Deleters, that de-allocate memory by layout (e.g. via `alloc::dealloc` or arena), can use
+    ///it instead of deriving layout from `T`, which may be erased.
+    ///
+    ///By default it calls `delete`.
+    unsafe fn delete_with_layout<T: ?Sized>(ptr: *mut T, layout: core::alloc::Layout) {
+        let _ = layout;
+        Self::delete::<T>(ptr)
+    }
+
+    #[inline(always)]
+    ///This function is called to dispose of array of `len` elements, starting at `ptr`.
+    ///
+    ///It is used by owners of arrays, such as [CVec](cvec/struct.CVec.html), [PtrArray](ptr_array/struct.PtrArray.html)
+    ///and parts of split slice.
+    ///
+    ///`Unique<[T], D>` invokes `delete_with_layout` on `Drop` instead, hence override must remain consistent with it.
+    ///
+    ///Deleters, that can dispose of whole array at once (e.g. single `free`), can override it.
+    ///
+    ///By default it calls `delete_with_layout` with slice, dropping its elements one by one.
+    unsafe fn delete_slice<T>(ptr: *mut T, len: usize) {
+        let ptr = core::ptr::slice_from_raw_parts_mut(ptr, len);
+        Self::delete_with_layout::<[T]>(ptr, core::alloc::Layout::for_value(&*ptr))
+    }
 }
 
 impl Deleter for () {
+    const SHARED: bool = true;
+
     #[inline(always)]
     unsafe fn delete<T: ?Sized>(_: *mut T) {}
 }
 
+///Deleter, which does nothing, intended for borrowed and static data.
+///
+///It is alias to `()`, which is used as no-op deleter throughout the crate.
+pub type NoopDeleter = ();
+
+///Marker of [Deleter](trait.Deleter.html), which disposes of pointer by its layout alone, regardless of pointee type.
+///
+///It enables [DynDeleter](trait.DynDeleter.html) for deleter.
+///
+///## Safety
+///
+///`delete_with_layout` must be correct for type-erased pointer, passed as pointer to `u8` with
+///layout of the original value, therefore it must neither rely on type of pointee nor call `delete`.
+pub unsafe trait LayoutDeleter: Deleter {
+}
+
+unsafe impl LayoutDeleter for () {
+}
+
+///Object-safe counterpart of [Deleter](trait.Deleter.html), which can be used as `dyn DynDeleter`.
+///
+///It is implemented for every [LayoutDeleter](trait.LayoutDeleter.html), allowing to choose
+///deletion strategy at runtime, regardless of pointee type.
+///Type is erased, therefore pointer is passed along with layout of the value, and destructor of
+///value is not invoked.
+///
+///```rust
+///# #[cfg(feature = "alloc")]
+///# fn main() {
+///use smart_ptr::{DeallocOnly, DynDeleter, StatefulUnique};
+///
+///let mut value = 1u64;
+///let pointers: [(*mut u64, &dyn DynDeleter); 2] = [
+///    (&mut value, &()),
+///    (Box::into_raw(Box::new(2u64)), &DeallocOnly),
+///];
+///for (ptr, deleter) in pointers {
+///    let ptr = unsafe { StatefulUnique::new(ptr, deleter) };
+///    assert_ne!(*ptr, 0);
+///}
+///# }
+///# #[cfg(not(feature = "alloc"))]
+///# fn main() {}
+///```
+pub trait DynDeleter {
+    ///This function is called on `Drop` with type-erased pointer and layout of the value.
+    unsafe fn delete_dyn(&self, ptr: *mut (), layout: core::alloc::Layout);
+}
+
+impl<D: LayoutDeleter> DynDeleter for D {
+    #[inline(always)]
+    unsafe fn delete_dyn(&self, ptr: *mut (), layout: core::alloc::Layout) {
+        D::delete_with_layout::<u8>(ptr as *mut u8, layout)
+    }
+}
+
+///Describes how to duplicate object, owned by pointer with `Deleter`.
+///
+///Enables `Clone` for [Unique](unique/struct.Unique.html) with deleter, implementing it.
+///
+///```rust
+///use smart_ptr::{Deleter, CloneWith, Unique};
+///
+///pub struct FooDeleter;
+///
+///impl Deleter for FooDeleter {
+///    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+///        let _ = Box::from_raw(ptr as *mut u32);
+///    }
+///}
+///
+///impl CloneWith<u32> for FooDeleter {
+///    unsafe fn clone_ptr(ptr: *const u32) -> *mut u32 {
+///        Box::into_raw(Box::new(*ptr))
+///    }
+///}
+///
+///let ptr = unsafe { Unique::<u32, FooDeleter>::new(Box::into_raw(Box::new(1))) };
+///let clone = ptr.clone();
+///assert_eq!(ptr, clone);
+///assert_ne!(ptr.get(), clone.get());
+///```
+pub trait CloneWith<T: ?Sized>: Deleter {
+    ///Creates copy of the object, returning pointer to be disposed of with the same `Deleter`.
+    ///
+    ///Returning null pointer results in panic.
+    unsafe fn clone_ptr(ptr: *const T) -> *mut T;
+}
+
+///Describes pointer, which owns object, allowing to write code generic over pointer type.
+///
+///```rust
+///use smart_ptr::{OwnedPtr, Unique};
+///
+///fn into_raw<P: OwnedPtr<Target = u32>>(ptr: P) -> core::ptr::NonNull<u32> {
+///    assert_eq!(unsafe { *ptr.get() }, 1);
+///    ptr.release()
+///}
+///
+///let mut value = 1u32;
+///let ptr = into_raw(Unique::<u32, ()>::from(&mut value));
+///let ptr = unsafe { <Unique<u32, ()> as OwnedPtr>::from_non_null(ptr) };
+///assert_eq!(*ptr, 1);
+///```
+pub trait OwnedPtr: Sized {
+    ///Type of the object.
+    type Target: ?Sized;
+
+    ///Gets underlying raw pointer.
+    fn get(&self) -> *mut Self::Target;
+
+    ///Releases the ownership and returns raw pointer, without dropping it.
+    fn release(self) -> core::ptr::NonNull<Self::Target>;
+
+    ///Creates instance from raw pointer, taking ownership of the object.
+    ///
+    ///User must guarantee that pointer can be disposed of by this type.
+    unsafe fn from_non_null(ptr: core::ptr::NonNull<Self::Target>) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> OwnedPtr for alloc::boxed::Box<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn get(&self) -> *mut Self::Target {
+        //Pointer is derived from shared reference, therefore it is only valid for reads
+        &**self as *const T as *mut T
+    }
+
+    #[inline(always)]
+    fn release(self) -> core::ptr::NonNull<Self::Target> {
+        unsafe {
+            core::ptr::NonNull::new_unchecked(alloc::boxed::Box::into_raw(self))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn from_non_null(ptr: core::ptr::NonNull<Self::Target>) -> Self {
+        alloc::boxed::Box::from_raw(ptr.as_ptr())
+    }
+}
+
 #[cfg(feature = "alloc")]
 ///Default Rust deleter.
 ///
@@ -45,9 +244,142 @@ impl Deleter for () {
 pub unsafe fn boxed_deleter<T: ?Sized>(ptr: *mut T) {
     debug_assert!(!ptr.is_null());
 
+    //Zero sized values are never allocated
+    if core::mem::size_of_val(&*ptr) == 0 {
+        core::ptr::drop_in_place(ptr);
+        return;
+    }
+
+    #[cfg(feature = "stats")]
+    stats::record_dealloc(core::mem::size_of_val(&*ptr));
     let _  = alloc::boxed::Box::from_raw(ptr);
 }
 
+#[cfg(any(feature = "debug-guards", feature = "diagnostics"))]
+///Hashes name of type, identifying types without `TypeId`, which is only available for `'static` types.
+///
+///Hash is never zero, allowing to use zero as empty value.
+pub(crate) fn type_hash<T: ?Sized>() -> usize {
+    //FNV-1a
+    let hash = core::any::type_name::<T>().bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    (hash as usize).max(1)
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+///Moves value into memory, allocated by global allocator, in a way compatible with `boxed_deleter`.
+///
+///Zero sized values are not allocated, instead dangling pointer is returned.
+pub(crate) fn boxed_raw<T>(val: T) -> *mut T {
+    match core::mem::size_of::<T>() {
+        0 => {
+            let ptr = core::ptr::NonNull::<T>::dangling().as_ptr();
+            unsafe {
+                ptr.write(val);
+            }
+            ptr
+        },
+        _size => {
+            #[cfg(feature = "stats")]
+            stats::record_alloc(_size);
+            alloc::boxed::Box::into_raw(alloc::boxed::Box::new(val))
+        },
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+///Fallible version of `boxed_raw`, which drops value if allocation fails.
+pub(crate) fn try_boxed_raw<T>(val: T) -> Result<*mut T, Error> {
+    let ptr = try_alloc_array::<T>(1, false)?;
+    unsafe {
+        ptr.write(val);
+    }
+    Ok(ptr)
+}
+
+#[cfg(feature = "alloc")]
+///Allocates memory for `len` elements, in a way compatible with `boxed_deleter`.
+///
+///If `zeroed` is true, memory is filled with zero bytes, otherwise it is uninitialized.
+///
+///Zero sized arrays are not allocated, instead dangling pointer is returned.
+pub(crate) fn try_alloc_array<T>(len: usize, zeroed: bool) -> Result<*mut T, Error> {
+    let layout = core::alloc::Layout::array::<T>(len).map_err(|_| Error::AllocFailed)?;
+    if layout.size() == 0 {
+        return Ok(core::ptr::NonNull::dangling().as_ptr());
+    }
+
+    let ptr = unsafe {
+        match zeroed {
+            true => alloc::alloc::alloc_zeroed(layout),
+            false => alloc::alloc::alloc(layout),
+        }
+    };
+    if ptr.is_null() {
+        return Err(Error::AllocFailed);
+    }
+    #[cfg(feature = "stats")]
+    stats::record_alloc(layout.size());
+    Ok(ptr as *mut T)
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+///Infallible version of `try_alloc_array`, which invokes `handle_alloc_error` on failure.
+pub(crate) fn alloc_array<T>(len: usize, zeroed: bool) -> *mut T {
+    match try_alloc_array::<T>(len, zeroed) {
+        Ok(ptr) => ptr,
+        Err(_) => alloc::alloc::handle_alloc_error(core::alloc::Layout::array::<T>(len).expect("capacity overflow")),
+    }
+}
+
+#[cfg(feature = "alloc")]
+///De-allocates memory, allocated by `alloc_array`, without dropping elements.
+pub(crate) unsafe fn dealloc_array<T>(ptr: *mut T, len: usize) {
+    let layout = core::alloc::Layout::array::<T>(len).expect("capacity overflow");
+    if layout.size() != 0 {
+        #[cfg(feature = "stats")]
+        stats::record_dealloc(layout.size());
+        alloc::alloc::dealloc(ptr as *mut u8, layout);
+    }
+}
+
+#[cfg(feature = "alloc")]
+///Allocates memory for `len` elements and initializes them in place, using `init`.
+///
+///If `init` panics, already initialized elements are dropped and memory is freed.
+pub(crate) fn try_init_array<T, F: FnMut(usize) -> T>(len: usize, mut init: F) -> Result<*mut T, Error> {
+    let ptr = try_alloc_array::<T>(len, false)?;
+    let mut guard = utils::ScopeGuard::new(0usize, |initialized| unsafe {
+        core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(ptr, initialized));
+        dealloc_array(ptr, len);
+    });
+
+    for idx in 0..len {
+        unsafe {
+            ptr.add(idx).write(init(idx));
+        }
+        *guard += 1;
+    }
+
+    utils::ScopeGuard::into_inner(guard);
+    Ok(ptr)
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+///Infallible version of `try_init_array`, which invokes `handle_alloc_error` on failure.
+pub(crate) fn init_array<T, F: FnMut(usize) -> T>(len: usize, init: F) -> *mut T {
+    match try_init_array(len, init) {
+        Ok(ptr) => ptr,
+        Err(_) => alloc::alloc::handle_alloc_error(core::alloc::Layout::array::<T>(len).expect("capacity overflow")),
+    }
+}
+
 #[derive(Default)]
 ///Deleter which uses global allocator via `Box`.
 ///
@@ -65,5 +397,364 @@ impl Deleter for GlobalDeleter {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<T: Clone> CloneWith<T> for GlobalDeleter {
+    #[inline]
+    unsafe fn clone_ptr(ptr: *const T) -> *mut T {
+        boxed_raw((*ptr).clone())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> CloneWith<[T]> for GlobalDeleter {
+    #[inline]
+    unsafe fn clone_ptr(ptr: *const [T]) -> *mut [T] {
+        let src = &*ptr;
+        let result = init_array(src.len(), |idx| src[idx].clone());
+        core::ptr::slice_from_raw_parts_mut(result, src.len())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CloneWith<str> for GlobalDeleter {
+    #[inline]
+    unsafe fn clone_ptr(ptr: *const str) -> *mut str {
+        let bytes = <Self as CloneWith<[u8]>>::clone_ptr(ptr as *const [u8]);
+        bytes as *mut str
+    }
+}
+
+#[cfg(feature = "std")]
+impl CloneWith<std::ffi::OsStr> for GlobalDeleter {
+    #[inline]
+    unsafe fn clone_ptr(ptr: *const std::ffi::OsStr) -> *mut std::ffi::OsStr {
+        std::boxed::Box::into_raw((*ptr).to_os_string().into_boxed_os_str())
+    }
+}
+
+#[cfg(feature = "std")]
+impl CloneWith<std::path::Path> for GlobalDeleter {
+    #[inline]
+    unsafe fn clone_ptr(ptr: *const std::path::Path) -> *mut std::path::Path {
+        std::boxed::Box::into_raw((*ptr).to_path_buf().into_boxed_path())
+    }
+}
+
+#[derive(Default)]
+///Deleter which re-creates `Rc` from pointer, decrementing its reference count.
+///
+///Therefore user must guarantee that pointer was created via `Rc::into_raw` with the same type.
+pub struct RcDeleter;
+
+#[cfg(feature = "alloc")]
+impl Deleter for RcDeleter {
+    const SHARED: bool = true;
+
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        debug_assert!(!ptr.is_null());
+
+        drop(alloc::rc::Rc::from_raw(ptr as *const T));
+    }
+}
+
+#[derive(Default)]
+///Deleter which re-creates `Arc` from pointer, decrementing its reference count.
+///
+///Therefore user must guarantee that pointer was created via `Arc::into_raw` with the same type.
+pub struct ArcDeleter;
+
+#[cfg(feature = "alloc")]
+impl Deleter for ArcDeleter {
+    const SHARED: bool = true;
+
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        debug_assert!(!ptr.is_null());
+
+        drop(alloc::sync::Arc::from_raw(ptr as *const T));
+    }
+}
+
+#[derive(Default)]
+///Deleter which only de-allocates memory using global allocator, without invoking destructor.
+///
+///It uses layout of the value to de-allocate memory, therefore pointer must be allocated by
+///global allocator with the same layout (e.g. via `Box`).
+///
+///Useful for buffers, which content is already destroyed or doesn't require destruction.
+pub struct DeallocOnly;
+
+#[cfg(feature = "alloc")]
+impl Deleter for DeallocOnly {
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        debug_assert!(!ptr.is_null());
+
+        Self::delete_with_layout::<T>(ptr, core::alloc::Layout::for_value(&*ptr))
+    }
+
+    #[inline]
+    unsafe fn delete_with_layout<T: ?Sized>(ptr: *mut T, layout: core::alloc::Layout) {
+        debug_assert!(!ptr.is_null());
+
+        //Zero sized values are never allocated
+        if layout.size() != 0 {
+            #[cfg(feature = "stats")]
+            stats::record_dealloc(layout.size());
+            alloc::alloc::dealloc(ptr as *mut u8, layout);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl LayoutDeleter for DeallocOnly {
+}
+
+///Deleter which invokes destructor and de-allocates memory using allocator `A`.
+///
+///Allocator is created via `Default` on every deletion, therefore it is intended for stateless
+///allocators, such as static instances of `GlobalAlloc`.
+///
+///It uses layout of the value to de-allocate memory, therefore pointer must be allocated by `A`
+///with the same layout.
+pub struct AllocDeleter<A>(core::marker::PhantomData<A>);
+
+impl<A: core::alloc::GlobalAlloc + Default> Deleter for AllocDeleter<A> {
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        debug_assert!(!ptr.is_null());
+
+        Self::delete_with_layout::<T>(ptr, core::alloc::Layout::for_value(&*ptr))
+    }
+
+    #[inline]
+    unsafe fn delete_with_layout<T: ?Sized>(ptr: *mut T, layout: core::alloc::Layout) {
+        debug_assert!(!ptr.is_null());
+
+        core::ptr::drop_in_place(ptr);
+        //Zero sized values are never allocated
+        if layout.size() != 0 {
+            A::default().dealloc(ptr as *mut u8, layout);
+        }
+    }
+}
+
+unsafe impl<A: core::alloc::GlobalAlloc + Default> LayoutDeleter for AllocDeleter<A> {
+}
+
+#[derive(Default)]
+///Deleter which only invokes destructor, without de-allocating memory.
+///
+///Useful for values, constructed in storage provided by caller (e.g. static buffers or arenas),
+///which must not be freed.
+pub struct DropInPlace;
+
+impl Deleter for DropInPlace {
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        debug_assert!(!ptr.is_null());
+
+        core::ptr::drop_in_place(ptr);
+    }
+}
+
+///Deleter wrapper, which aborts process if inner deleter panics.
+///
+///Prevents unwinding from deleter through C frames, which is undefined behavior.
+///
+///Without `std` abort is achieved by panicking during unwinding.
+pub struct AbortOnPanic<D>(core::marker::PhantomData<D>);
+
+//Aborts on `Drop`, therefore it must be forgotten once guarded code finishes.
+struct Abort;
+
+impl Drop for Abort {
+    #[cold]
+    #[inline(never)]
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        std::process::abort();
+        #[cfg(not(feature = "std"))]
+        panic!("Deleter panicked, aborting");
+    }
+}
+
+impl<D: Deleter> Deleter for AbortOnPanic<D> {
+    const SHARED: bool = D::SHARED;
+
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        let abort = Abort;
+        D::delete::<T>(ptr);
+        core::mem::forget(abort);
+    }
+
+    #[inline]
+    unsafe fn delete_with_layout<T: ?Sized>(ptr: *mut T, layout: core::alloc::Layout) {
+        let abort = Abort;
+        D::delete_with_layout::<T>(ptr, layout);
+        core::mem::forget(abort);
+    }
+
+    #[inline]
+    unsafe fn delete_slice<T>(ptr: *mut T, len: usize) {
+        let abort = Abort;
+        D::delete_slice::<T>(ptr, len);
+        core::mem::forget(abort);
+    }
+}
+
+unsafe impl<D: LayoutDeleter> LayoutDeleter for AbortOnPanic<D> {
+}
+
+#[cfg(feature = "std")]
+///Describes how to report panic, caught by [CatchUnwind](struct.CatchUnwind.html).
+pub trait PanicReporter {
+    ///Called with payload of the panic.
+    fn report(payload: std::boxed::Box<dyn core::any::Any + Send>);
+}
+
+#[cfg(feature = "std")]
+impl PanicReporter for () {
+    #[inline(always)]
+    fn report(_: std::boxed::Box<dyn core::any::Any + Send>) {
+    }
+}
+
+#[cfg(feature = "std")]
+///Deleter wrapper, which catches panic of inner deleter, reporting it via `PanicReporter`.
+///
+///Prevents single failing destructor from interrupting destruction of the rest of objects.
+///
+///Note that memory of the object is most likely leaked, if deleter panics.
+///
+///```rust
+///use smart_ptr::{Deleter, CatchUnwind, PanicReporter, Unique};
+///
+///pub struct PanicDeleter;
+///
+///impl Deleter for PanicDeleter {
+///    unsafe fn delete<T: ?Sized>(_: *mut T) {
+///        panic!("Cannot delete");
+///    }
+///}
+///
+///pub struct Reporter;
+///
+///impl PanicReporter for Reporter {
+///    fn report(payload: Box<dyn core::any::Any + Send>) {
+///        assert_eq!(payload.downcast_ref::<&str>(), Some(&"Cannot delete"));
+///    }
+///}
+///
+///let mut value = 0u32;
+///let ptr = unsafe { Unique::<_, CatchUnwind<PanicDeleter, Reporter>>::new(&mut value) };
+///drop(ptr);
+///```
+pub struct CatchUnwind<D, R = ()>(core::marker::PhantomData<(D, R)>);
+
+#[cfg(feature = "std")]
+impl<D: Deleter, R: PanicReporter> Deleter for CatchUnwind<D, R> {
+    const SHARED: bool = D::SHARED;
+
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            D::delete::<T>(ptr)
+        }));
+
+        if let Err(payload) = result {
+            R::report(payload);
+        }
+    }
+
+    #[inline]
+    unsafe fn delete_with_layout<T: ?Sized>(ptr: *mut T, layout: core::alloc::Layout) {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            D::delete_with_layout::<T>(ptr, layout)
+        }));
+
+        if let Err(payload) = result {
+            R::report(payload);
+        }
+    }
+
+    #[inline]
+    unsafe fn delete_slice<T>(ptr: *mut T, len: usize) {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            D::delete_slice::<T>(ptr, len)
+        }));
+
+        if let Err(payload) = result {
+            R::report(payload);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+unsafe impl<D: LayoutDeleter, R: PanicReporter> LayoutDeleter for CatchUnwind<D, R> {
+}
+
+pub mod error;
+pub use error::{Error, NullPtrError};
+pub mod aligned;
+pub use aligned::AlignedDeleter;
 pub mod unique;
-pub use unique::Unique;
+pub use unique::{Unique, Owned};
+pub mod unique_const;
+pub use unique_const::UniqueConst;
+pub mod cell;
+pub use cell::UniqueCell;
+pub mod stateful;
+pub use stateful::StatefulUnique;
+#[cfg(feature = "alloc")]
+pub mod split;
+pub mod com;
+pub use com::ComPtr;
+pub mod intrusive;
+pub mod waker;
+pub use intrusive::IntrusiveShared;
+pub mod pool;
+pub mod arena;
+pub mod offset;
+pub use offset::OffsetPtr;
+pub mod compressed;
+pub mod once;
+pub use once::OnceUnique;
+pub mod atomic;
+pub use atomic::AtomicUnique;
+pub mod tagged;
+pub mod rcu;
+pub use rcu::RcuCell;
+pub mod hazard;
+pub mod thread;
+pub mod utils;
+pub mod cvec;
+#[cfg(feature = "leak-tracking")]
+pub mod leak;
+#[cfg(feature = "leak-tracking")]
+pub use leak::leak_report;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::live_count;
+#[cfg(feature = "debug-guards")]
+mod guards;
+#[cfg(feature = "stats")]
+pub mod stats;
+#[cfg(feature = "test-util")]
+pub mod mock;
+#[cfg(feature = "stats")]
+pub use stats::stats;
+pub use cvec::CVec;
+pub mod ptr_array;
+pub use ptr_array::{PtrArray, NullTerminatedArrayDeleter};
+pub mod wide;
+pub use wide::WideStrPtr;
+#[cfg(all(feature = "std", any(target_os = "linux", target_os = "macos")))]
+pub mod shm;
+pub mod maybe_owned;
+pub use maybe_owned::MaybeOwned;
+#[cfg(feature = "alloc")]
+pub mod watch;
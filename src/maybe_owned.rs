@@ -0,0 +1,141 @@
+//!Pointer, that is either borrowed or owned.
+
+use core::fmt;
+
+use crate::{Deleter, CloneWith, Unique};
+
+///Either borrowed reference or owned pointer, similar to `Cow`.
+///
+///Useful for FFI buffers, that can be either caller-owned or callee-owned.
+///
+///```rust
+///use smart_ptr::{Deleter, CloneWith, MaybeOwned};
+///
+///pub struct StringDeleter;
+///
+///impl Deleter for StringDeleter {
+///    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+///        let _ = Box::from_raw(ptr as *mut String);
+///    }
+///}
+///
+///impl CloneWith<String> for StringDeleter {
+///    unsafe fn clone_ptr(ptr: *const String) -> *mut String {
+///        Box::into_raw(Box::new((*ptr).clone()))
+///    }
+///}
+///
+///let value = String::from("borrowed");
+///let mut ptr = MaybeOwned::<_, StringDeleter>::from(&value);
+///assert!(!ptr.is_owned());
+///
+///ptr.to_mut().push_str(" and changed");
+///assert!(ptr.is_owned());
+///assert_eq!(*ptr, "borrowed and changed");
+///assert_eq!(value, "borrowed");
+///```
+pub enum MaybeOwned<'a, T: ?Sized, D: Deleter> {
+    ///Borrowed reference.
+    Borrowed(&'a T),
+    ///Owned pointer.
+    Owned(Unique<'a, T, D>),
+}
+
+impl<'a, T: ?Sized, D: Deleter> MaybeOwned<'a, T, D> {
+    #[inline(always)]
+    ///Returns whether pointer is owned.
+    pub fn is_owned(&self) -> bool {
+        match self {
+            MaybeOwned::Borrowed(_) => false,
+            MaybeOwned::Owned(_) => true,
+        }
+    }
+
+    #[inline(always)]
+    ///Gets reference to underlying data.
+    pub fn as_ref(&self) -> &T {
+        match self {
+            MaybeOwned::Borrowed(value) => value,
+            MaybeOwned::Owned(ptr) => ptr,
+        }
+    }
+}
+
+impl<'a, T: ?Sized, D: CloneWith<T>> MaybeOwned<'a, T, D> {
+    #[inline]
+    ///Gets mutable reference to underlying data, cloning it if it is borrowed.
+    pub fn to_mut(&mut self) -> &mut T {
+        if let MaybeOwned::Borrowed(value) = self {
+            *self = MaybeOwned::Owned(clone_owned(value));
+        }
+
+        match self {
+            MaybeOwned::Owned(ptr) => ptr,
+            //Borrowed value is replaced above
+            MaybeOwned::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    #[inline]
+    ///Converts into owned pointer, cloning data if it is borrowed.
+    pub fn into_owned(self) -> Unique<'a, T, D> {
+        match self {
+            MaybeOwned::Borrowed(value) => clone_owned(value),
+            MaybeOwned::Owned(ptr) => ptr,
+        }
+    }
+}
+
+#[inline]
+fn clone_owned<'a, T: ?Sized, D: CloneWith<T>>(value: &T) -> Unique<'a, T, D> {
+    unsafe {
+        Unique::new(D::clone_ptr(value))
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> From<&'a T> for MaybeOwned<'a, T, D> {
+    #[inline(always)]
+    fn from(value: &'a T) -> Self {
+        MaybeOwned::Borrowed(value)
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> From<Unique<'a, T, D>> for MaybeOwned<'a, T, D> {
+    #[inline(always)]
+    fn from(ptr: Unique<'a, T, D>) -> Self {
+        MaybeOwned::Owned(ptr)
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> core::ops::Deref for MaybeOwned<'a, T, D> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<'a, T: ?Sized + fmt::Debug, D: Deleter> fmt::Debug for MaybeOwned<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_ref(), fmt)
+    }
+}
+
+impl<'a, T: ?Sized + fmt::Display, D: Deleter> fmt::Display for MaybeOwned<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_ref(), fmt)
+    }
+}
+
+impl<'a, T: ?Sized + PartialEq, D: Deleter> PartialEq<Self> for MaybeOwned<'a, T, D> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<'a, T: ?Sized + Eq, D: Deleter> Eq for MaybeOwned<'a, T, D> {
+}
@@ -0,0 +1,116 @@
+//!Deleter, which records deletions, enabled by `test-util` feature.
+//!
+//!Log of deletions is kept per thread, therefore tests, running in parallel, don't interfere with
+//!each other, as long as pointers are dropped on the thread of the test.
+//!
+//!```rust
+//!use smart_ptr::Unique;
+//!use smart_ptr::mock::{self, MockDeleter};
+//!
+//!let mut first = 1u32;
+//!let mut second = 2u64;
+//!drop(unsafe { Unique::<u32, MockDeleter>::new(&mut first) });
+//!drop(unsafe { Unique::<u64, MockDeleter>::new(&mut second) });
+//!
+//!let deletions = mock::deletions();
+//!assert_eq!(deletions[0].address, &first as *const u32 as usize);
+//!assert_eq!(deletions[1].type_name, "u64");
+//!mock::assert_deletions(2);
+//!```
+
+use core::marker;
+use core::cell::RefCell;
+
+use alloc::vec::Vec;
+
+use crate::{Deleter, LayoutDeleter};
+
+std::thread_local! {
+    static DELETIONS: RefCell<Vec<Deletion>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///Record of single deletion.
+pub struct Deletion {
+    ///Address of deleted pointer.
+    pub address: usize,
+    ///Name of the pointer's type.
+    pub type_name: &'static str,
+    ///Index of deletion within log of the current thread.
+    pub order: usize,
+}
+
+#[inline]
+fn record<T: ?Sized>(ptr: *mut T) {
+    DELETIONS.with(|deletions| {
+        let mut deletions = deletions.borrow_mut();
+        let order = deletions.len();
+        deletions.push(Deletion {
+            address: ptr as *mut u8 as usize,
+            type_name: core::any::type_name::<T>(),
+            order,
+        });
+    });
+}
+
+///Deleter, which records every deletion into log of the current thread, before passing pointer to deleter `D`.
+///
+///By default `D` does nothing, therefore pointer is never freed.
+pub struct MockDeleter<D: Deleter = ()>(marker::PhantomData<D>);
+
+impl<D: Deleter> Deleter for MockDeleter<D> {
+    const SHARED: bool = D::SHARED;
+
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        record(ptr);
+        D::delete::<T>(ptr)
+    }
+
+    #[inline]
+    unsafe fn delete_with_layout<T: ?Sized>(ptr: *mut T, layout: core::alloc::Layout) {
+        record(ptr);
+        D::delete_with_layout::<T>(ptr, layout)
+    }
+
+    #[inline]
+    unsafe fn delete_slice<T>(ptr: *mut T, len: usize) {
+        record(core::ptr::slice_from_raw_parts_mut(ptr, len));
+        D::delete_slice::<T>(ptr, len)
+    }
+}
+
+unsafe impl<D: LayoutDeleter> LayoutDeleter for MockDeleter<D> {
+}
+
+///Returns deletions, recorded on the current thread.
+pub fn deletions() -> Vec<Deletion> {
+    DELETIONS.with(|deletions| deletions.borrow().clone())
+}
+
+///Returns deletions, recorded on the current thread, clearing its log.
+pub fn take_deletions() -> Vec<Deletion> {
+    DELETIONS.with(|deletions| core::mem::take(&mut *deletions.borrow_mut()))
+}
+
+///Clears log of deletions of the current thread.
+pub fn reset() {
+    drop(take_deletions());
+}
+
+#[track_caller]
+///Asserts that exactly `expected` deletions are recorded on the current thread, without deleting
+///the same address twice, clearing its log.
+///
+///Note that address, which is re-used after being freed, is reported as double free, therefore
+///log should be cleared in between.
+pub fn assert_deletions(expected: usize) {
+    let deletions = take_deletions();
+    assert_eq!(deletions.len(), expected, "Unexpected number of deletions: {:?}", deletions);
+
+    for (idx, deletion) in deletions.iter().enumerate() {
+        if let Some(duplicate) = deletions[..idx].iter().find(|prev| prev.address == deletion.address) {
+            panic!("Double free of 0x{:x}: {:?} and {:?}", deletion.address, duplicate, deletion);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//!Self-relative pointer implementation
+
+use core::{fmt, marker};
+
+//Offset, which is never valid, as it points inside of the pointer itself.
+const NULL_OFFSET: isize = 1;
+
+#[repr(transparent)]
+///Pointer, that stores offset relative to its own address.
+///
+///Data structure, which consists of such pointers, remains valid as long as it is moved as a whole
+///(e.g. shared memory segment mapped at different base address in different processes).
+///
+///Offset of `1` is used to represent null pointer, as it would point inside of the pointer itself,
+///while zero offset is valid for value, that starts with the pointer (e.g. node of circular list).
+///
+///Due to nature of the pointer, it cannot be copied or cloned, as new copy would point elsewhere.
+///
+///```rust
+///use smart_ptr::OffsetPtr;
+///
+///#[repr(C)]
+///struct Node {
+///    value: u32,
+///    next: OffsetPtr<u32>,
+///}
+///
+///let mut node = Node {
+///    value: 1,
+///    next: OffsetPtr::null(),
+///};
+///assert!(node.next.is_null());
+///node.next.set(&node.value);
+///assert_eq!(unsafe { node.next.as_ref() }, Some(&1));
+///```
+pub struct OffsetPtr<T> {
+    offset: isize,
+    _traits: marker::PhantomData<*mut T>,
+}
+
+impl<T> OffsetPtr<T> {
+    #[inline(always)]
+    ///Creates null pointer.
+    pub const fn null() -> Self {
+        Self {
+            offset: NULL_OFFSET,
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns whether pointer is null.
+    pub const fn is_null(&self) -> bool {
+        self.offset == NULL_OFFSET
+    }
+
+    #[inline(always)]
+    ///Returns stored offset relative to pointer's own address.
+    pub const fn offset(&self) -> isize {
+        self.offset
+    }
+
+    #[inline]
+    ///Sets pointer to point at `ptr`, storing its offset relative to pointer's address.
+    ///
+    ///Null `ptr` results in null pointer.
+    pub fn set(&mut self, ptr: *const T) {
+        self.offset = match ptr.is_null() {
+            true => NULL_OFFSET,
+            false => (ptr as isize).wrapping_sub(self as *const Self as isize),
+        };
+    }
+
+    #[inline]
+    ///Gets underlying raw pointer, calculated using pointer's current address.
+    pub fn get(&self) -> *mut T {
+        match self.offset {
+            NULL_OFFSET => core::ptr::null_mut(),
+            offset => (self as *const Self as *const u8).wrapping_offset(offset) as *mut T,
+        }
+    }
+
+    #[inline]
+    ///Gets reference to pointed value, if pointer is not null.
+    ///
+    ///User must guarantee that pointer points to valid value.
+    pub unsafe fn as_ref(&self) -> Option<&T> {
+        self.get().as_ref()
+    }
+
+    #[inline]
+    ///Gets mutable reference to pointed value, if pointer is not null.
+    ///
+    ///User must guarantee that pointer points to valid value, which is not aliased.
+    pub unsafe fn as_mut(&mut self) -> Option<&mut T> {
+        self.get().as_mut()
+    }
+}
+
+impl<T> Default for OffsetPtr<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl<T> fmt::Pointer for OffsetPtr<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.get(), fmt)
+    }
+}
+
+impl<T> fmt::Debug for OffsetPtr<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.get(), fmt)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for OffsetPtr<T> {
+    #[inline(always)]
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(fmt, "{=str}@{=usize:#x}", core::any::type_name::<T>(), self.get() as *const u8 as usize)
+    }
+}
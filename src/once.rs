@@ -0,0 +1,124 @@
+//!One-time initialized owning pointer implementation
+
+use core::{fmt, ptr, marker};
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::{Deleter, Unique};
+
+///Cell, that can be set with owned pointer exactly once.
+///
+///It can be used in `static` context, for example to store global context of C library.
+///
+///Once set, pointer is owned by cell until it is explicitly torn down or cell is dropped.
+///
+///```rust
+///use smart_ptr::{OnceUnique, Unique};
+///
+///static CONTEXT: OnceUnique<u32, ()> = OnceUnique::new();
+///static mut VALUE: u32 = 1;
+///
+///assert!(CONTEXT.get().is_none());
+///let ptr = unsafe { Unique::<u32, ()>::new(core::ptr::addr_of_mut!(VALUE)) };
+///assert!(CONTEXT.set(ptr).is_ok());
+///assert_eq!(CONTEXT.get(), Some(&1));
+///```
+pub struct OnceUnique<'a, T, D: Deleter> {
+    inner: AtomicPtr<T>,
+    _traits: marker::PhantomData<Unique<'a, T, D>>,
+}
+
+impl<'a, T, D: Deleter> OnceUnique<'a, T, D> {
+    #[inline(always)]
+    ///Creates new empty cell.
+    pub const fn new() -> Self {
+        Self {
+            inner: AtomicPtr::new(ptr::null_mut()),
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Sets pointer, if cell is empty.
+    ///
+    ///Returns pointer back, if cell is already set.
+    pub fn set(&self, ptr: Unique<'a, T, D>) -> Result<(), Unique<'a, T, D>> {
+        let raw = ptr.get();
+        match self.inner.compare_exchange(ptr::null_mut(), raw, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => {
+                ptr.release();
+                Ok(())
+            },
+            Err(_) => Err(ptr),
+        }
+    }
+
+    #[inline]
+    ///Gets reference to the value, if cell is set.
+    pub fn get(&self) -> Option<&T> {
+        unsafe {
+            self.inner.load(Ordering::Acquire).as_ref()
+        }
+    }
+
+    #[inline]
+    ///Gets reference to the value, initializing cell with `init` if it is empty.
+    ///
+    ///If multiple threads race to initialize cell, only one pointer is stored, while the rest is
+    ///disposed of.
+    pub fn get_or_init<F: FnOnce() -> Unique<'a, T, D>>(&self, init: F) -> &T {
+        if let Some(value) = self.get() {
+            return value;
+        }
+
+        let _ = self.set(init());
+        self.get().expect("cell to be set")
+    }
+
+    #[inline]
+    ///Takes pointer out of the cell, leaving it empty.
+    pub fn take(&mut self) -> Option<Unique<'a, T, D>> {
+        let ptr = core::mem::replace(self.inner.get_mut(), ptr::null_mut());
+        unsafe {
+            Unique::from_ptr(ptr)
+        }
+    }
+
+    #[inline]
+    ///Tears down cell, disposing of pointer with deleter.
+    ///
+    ///Returns `true` if cell was set.
+    ///
+    ///# Safety
+    ///
+    ///User must guarantee that there are no outstanding references to the value,
+    ///which are obtained via `get`.
+    pub unsafe fn teardown(&self) -> bool {
+        let ptr = self.inner.swap(ptr::null_mut(), Ordering::AcqRel);
+        Unique::<T, D>::from_ptr(ptr).is_some()
+    }
+}
+
+impl<'a, T, D: Deleter> Default for OnceUnique<'a, T, D> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T, D: Deleter> Drop for OnceUnique<'a, T, D> {
+    #[inline]
+    fn drop(&mut self) {
+        let _ = self.take();
+    }
+}
+
+impl<'a, T: fmt::Debug, D: Deleter> fmt::Debug for OnceUnique<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.get(), fmt)
+    }
+}
+
+unsafe impl<'a, T: Send, D: Deleter> Send for OnceUnique<'a, T, D> {}
+
+unsafe impl<'a, T: Send + Sync, D: Deleter> Sync for OnceUnique<'a, T, D> {}
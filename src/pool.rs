@@ -0,0 +1,184 @@
+//!Object pool implementation
+
+use core::{mem, ptr};
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::Deleter;
+
+#[repr(C)]
+pub(crate) struct Slot<T> {
+    //Must be first field as `PoolDeleter` relies on it being right before value
+    is_occupied: AtomicBool,
+    value: core::cell::UnsafeCell<mem::MaybeUninit<T>>,
+}
+
+impl<T> Slot<T> {
+    #[inline(always)]
+    pub(crate) const fn new() -> Self {
+        Self {
+            is_occupied: AtomicBool::new(false),
+            value: core::cell::UnsafeCell::new(mem::MaybeUninit::uninit()),
+        }
+    }
+
+    #[inline]
+    ///Attempts to occupy slot, returning pointer to initialized value on success.
+    pub(crate) fn acquire(&self, val: T) -> Result<*mut T, T> {
+        match self.is_occupied.compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed) {
+            Ok(_) => unsafe {
+                let slot = self as *const Self;
+                let ptr = ptr::addr_of!((*slot).value) as *mut T;
+                ptr.write(val);
+                Ok(ptr)
+            },
+            Err(_) => Err(val),
+        }
+    }
+}
+
+//Places value into first free slot, returning it back if there is none.
+fn acquire<T>(slots: &[Slot<T>], mut val: T) -> Result<crate::Unique<'_, T, PoolDeleter>, T> {
+    for slot in slots {
+        match slot.acquire(val) {
+            Ok(ptr) => return Ok(unsafe {
+                crate::Unique::from_ptr_unchecked(ptr)
+            }),
+            Err(returned) => val = returned,
+        }
+    }
+
+    Err(val)
+}
+
+#[derive(Default)]
+///Deleter which returns object to the pool it was acquired from.
+///
+///It runs destructor of the object and then marks its slot as free.
+///
+///Therefore it must only be used with pointers that were acquired from pool.
+pub struct PoolDeleter;
+
+impl Deleter for PoolDeleter {
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        //Value is placed right after flag, aligned according to its type
+        let offset = mem::align_of_val(&*ptr);
+        ptr::drop_in_place(ptr);
+
+        let is_occupied = &*((ptr as *mut u8).sub(offset) as *const AtomicBool);
+        is_occupied.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(feature = "alloc")]
+///Fixed capacity pool of objects, allocated using global allocator.
+///
+///Acquired objects are returned to the pool on `Drop`, without any de-allocation.
+///
+///Pool can be safely shared between threads.
+///
+///```rust
+///use smart_ptr::pool::Pool;
+///
+///let pool = Pool::new(1);
+///let value = pool.acquire(1).expect("to have free slot");
+///assert_eq!(*value, 1);
+///assert_eq!(pool.acquire(2).unwrap_err(), 2);
+///
+///drop(value);
+///let value = pool.acquire(3).expect("to have free slot");
+///assert_eq!(*value, 3);
+///```
+pub struct Pool<T> {
+    slots: alloc::boxed::Box<[Slot<T>]>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Pool<T> {
+    ///Creates new pool with specified capacity.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            slots: (0..capacity).map(|_| Slot::new()).collect(),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns maximum number of objects in the pool.
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    ///Places value into free slot of the pool.
+    ///
+    ///Returns value back if there is no free slot.
+    pub fn acquire(&self, val: T) -> Result<crate::Unique<'_, T, PoolDeleter>, T> {
+        acquire(&self.slots, val)
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T: Send> Send for Pool<T> {}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T: Send> Sync for Pool<T> {}
+
+///Alias to [PoolDeleter](struct.PoolDeleter.html), used by [StaticPool](struct.StaticPool.html).
+pub type StaticPoolDeleter = PoolDeleter;
+
+///Fixed capacity pool of objects, which stores them inline, without any allocation.
+///
+///It can be created in const context, therefore it can be placed into `static`, providing owned
+///pointers on targets without allocator.
+///
+///Acquired objects are returned to the pool on `Drop`.
+///
+///```rust
+///use smart_ptr::pool::StaticPool;
+///
+///static POOL: StaticPool<u32, 1> = StaticPool::new();
+///
+///let value = POOL.acquire(1).expect("to have free slot");
+///assert_eq!(*value, 1);
+///assert_eq!(POOL.acquire(2).unwrap_err(), 2);
+///
+///drop(value);
+///let value = POOL.acquire(3).expect("to have free slot");
+///assert_eq!(*value, 3);
+///```
+pub struct StaticPool<T, const N: usize> {
+    slots: [Slot<T>; N],
+}
+
+impl<T, const N: usize> StaticPool<T, N> {
+    #[inline(always)]
+    ///Creates new pool.
+    pub const fn new() -> Self {
+        Self {
+            slots: [const { Slot::new() }; N],
+        }
+    }
+
+    #[inline(always)]
+    ///Returns maximum number of objects in the pool.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    ///Places value into free slot of the pool.
+    ///
+    ///Returns value back if there is no free slot.
+    pub fn acquire(&self, val: T) -> Result<crate::Unique<'_, T, StaticPoolDeleter>, T> {
+        acquire(&self.slots, val)
+    }
+}
+
+impl<T, const N: usize> Default for StaticPool<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<T: Send, const N: usize> Send for StaticPool<T, N> {}
+
+unsafe impl<T: Send, const N: usize> Sync for StaticPool<T, N> {}
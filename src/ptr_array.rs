@@ -0,0 +1,267 @@
+//!Owned array of pointers, like `argv` or lists of handles, returned by C libraries.
+
+use core::{mem, fmt, ptr, marker};
+
+use crate::{Deleter, Unique};
+
+///Array of pointers, that owns both array and its elements.
+///
+///Array is either length-prefixed or null-terminated, and all its elements must be non-null.
+///
+///On `Drop` it disposes of each element with deleter `D`, followed by array itself with deleter `A`.
+///Array deleter receives slice of all elements, including null terminator, if any.
+///
+///```rust
+///use smart_ptr::PtrArray;
+///
+///let mut first = 1u32;
+///let mut second = 2u32;
+///let mut array = [&mut first as *mut u32, &mut second as *mut u32, core::ptr::null_mut()];
+///
+///let array = unsafe { PtrArray::<u32, (), ()>::from_null_terminated(array.as_mut_ptr()) };
+///assert_eq!(array.len(), 2);
+///assert_eq!(array.iter().map(|elem| **elem).collect::<Vec<_>>(), [1, 2]);
+///```
+pub struct PtrArray<'a, T, D: Deleter, A: Deleter> {
+    ptr: ptr::NonNull<*mut T>,
+    len: usize,
+    size: usize,
+    _traits: marker::PhantomData<(Unique<'a, T, D>, &'a A)>,
+}
+
+impl<'a, T, D: Deleter, A: Deleter> PtrArray<'a, T, D, A> {
+    #[inline]
+    ///Creates new instance from pointer to array of `len` elements.
+    ///
+    ///User must guarantee that all elements are non-null.
+    ///
+    ///# Panics
+    ///
+    ///- If pointer is null
+    pub unsafe fn new(ptr: *mut *mut T, len: usize) -> Self {
+        assert!(!ptr.is_null());
+
+        Self::from_raw_parts(ptr, len, len)
+    }
+
+    #[inline]
+    ///Creates new instance from pointer to array, terminated by null element.
+    ///
+    ///# Panics
+    ///
+    ///- If pointer is null
+    pub unsafe fn from_null_terminated(ptr: *mut *mut T) -> Self {
+        assert!(!ptr.is_null());
+
+        let mut len = 0;
+        while !(*ptr.add(len)).is_null() {
+            len += 1;
+        }
+
+        Self::from_raw_parts(ptr, len, len + 1)
+    }
+
+    #[inline]
+    unsafe fn from_raw_parts(ptr: *mut *mut T, len: usize, size: usize) -> Self {
+        debug_assert!((0..len).all(|idx| !(*ptr.add(idx)).is_null()));
+
+        Self {
+            ptr: ptr::NonNull::new_unchecked(ptr),
+            len,
+            size,
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of elements, excluding null terminator.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    ///Returns whether array has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer to array.
+    pub fn as_ptr(&self) -> *mut *mut T {
+        self.ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    ///Gets elements as slice of pointers.
+    pub fn as_slice(&self) -> &[Unique<'a, T, D>] {
+        //Unique is transparent wrapper over non-null pointer
+        unsafe {
+            core::slice::from_raw_parts(self.ptr.as_ptr() as *const Unique<'a, T, D>, self.len)
+        }
+    }
+
+    #[inline(always)]
+    ///Gets elements as mutable slice of pointers.
+    pub fn as_mut_slice(&mut self) -> &mut [Unique<'a, T, D>] {
+        unsafe {
+            core::slice::from_raw_parts_mut(self.ptr.as_ptr() as *mut Unique<'a, T, D>, self.len)
+        }
+    }
+
+    #[inline]
+    ///Releases the ownership and returns raw pointer to array and number of its elements,
+    ///without dropping it.
+    pub fn release(self) -> (ptr::NonNull<*mut T>, usize) {
+        let result = (self.ptr, self.len);
+        mem::forget(self);
+        result
+    }
+}
+
+impl<'a, T, D: Deleter, A: Deleter> Drop for PtrArray<'a, T, D, A> {
+    #[inline]
+    fn drop(&mut self) {
+        drop(IntoIter::<T, D, A> {
+            ptr: self.ptr,
+            idx: 0,
+            len: self.len,
+            size: self.size,
+            _traits: marker::PhantomData,
+        });
+    }
+}
+
+impl<'a, T, D: Deleter, A: Deleter> core::ops::Deref for PtrArray<'a, T, D, A> {
+    type Target = [Unique<'a, T, D>];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<'a, T, D: Deleter, A: Deleter> core::ops::DerefMut for PtrArray<'a, T, D, A> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<'a, T: fmt::Debug, D: Deleter, A: Deleter> fmt::Debug for PtrArray<'a, T, D, A> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), fmt)
+    }
+}
+
+impl<'a, T, D: Deleter, A: Deleter> IntoIterator for PtrArray<'a, T, D, A> {
+    type Item = Unique<'a, T, D>;
+    type IntoIter = IntoIter<'a, T, D, A>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let result = IntoIter {
+            ptr: self.ptr,
+            idx: 0,
+            len: self.len,
+            size: self.size,
+            _traits: marker::PhantomData,
+        };
+        mem::forget(self);
+        result
+    }
+}
+
+impl<'a, 'b, T, D: Deleter, A: Deleter> IntoIterator for &'b PtrArray<'a, T, D, A> {
+    type Item = &'b Unique<'a, T, D>;
+    type IntoIter = core::slice::Iter<'b, Unique<'a, T, D>>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+unsafe impl<'a, T: Send, D: Deleter, A: Deleter> Send for PtrArray<'a, T, D, A> {}
+
+unsafe impl<'a, T: Sync, D: Deleter, A: Deleter> Sync for PtrArray<'a, T, D, A> {}
+
+///Deleter of null-terminated array of pointers to `T`, like `argv` or `environ`.
+///
+///It disposes of each element with deleter `D`, followed by array itself with deleter `A`, in the
+///same way as [PtrArray](struct.PtrArray.html).
+///
+///It is intended for pointers to the first element of array, i.e. `*mut *mut T`.
+///
+///```rust
+///use smart_ptr::{NullTerminatedArrayDeleter, Unique};
+///
+///let mut first = 1u32;
+///let mut array = [&mut first as *mut u32, core::ptr::null_mut()];
+///
+///let argv = unsafe { Unique::<*mut u32, NullTerminatedArrayDeleter<u32, (), ()>>::new(array.as_mut_ptr()) };
+///assert_eq!(unsafe { **argv }, 1);
+///```
+pub struct NullTerminatedArrayDeleter<T, D: Deleter, A: Deleter = D>(marker::PhantomData<(*mut T, D, A)>);
+
+impl<T, D: Deleter, A: Deleter> Deleter for NullTerminatedArrayDeleter<T, D, A> {
+    #[inline]
+    unsafe fn delete<P: ?Sized>(ptr: *mut P) {
+        drop(PtrArray::<T, D, A>::from_null_terminated(ptr as *mut u8 as *mut *mut T));
+    }
+}
+
+///Iterator, that moves elements out of [PtrArray](struct.PtrArray.html).
+///
+///On `Drop` it disposes of remaining elements and array itself.
+pub struct IntoIter<'a, T, D: Deleter, A: Deleter> {
+    ptr: ptr::NonNull<*mut T>,
+    idx: usize,
+    len: usize,
+    size: usize,
+    _traits: marker::PhantomData<(Unique<'a, T, D>, &'a A)>,
+}
+
+impl<'a, T, D: Deleter, A: Deleter> Iterator for IntoIter<'a, T, D, A> {
+    type Item = Unique<'a, T, D>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx == self.len {
+            return None;
+        }
+
+        let elem = unsafe {
+            Unique::from_ptr_unchecked(*self.ptr.as_ptr().add(self.idx))
+        };
+        self.idx += 1;
+        Some(elem)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, D: Deleter, A: Deleter> ExactSizeIterator for IntoIter<'a, T, D, A> {
+}
+
+impl<'a, T, D: Deleter, A: Deleter> Drop for IntoIter<'a, T, D, A> {
+    fn drop(&mut self) {
+        //Array must be disposed of, even if element's deleter panics
+        let (array, size) = (self.ptr.as_ptr(), self.size);
+        let _guard = crate::utils::CallOnDrop::new(|| unsafe {
+            A::delete_slice::<*mut T>(array, size)
+        });
+
+        for elem in self {
+            drop(elem);
+        }
+    }
+}
+
+unsafe impl<'a, T: Send, D: Deleter, A: Deleter> Send for IntoIter<'a, T, D, A> {}
+
+unsafe impl<'a, T: Sync, D: Deleter, A: Deleter> Sync for IntoIter<'a, T, D, A> {}
@@ -0,0 +1,173 @@
+//!Read-copy-update cell implementation
+
+use core::{mem, fmt, ptr, marker, hint};
+use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+use crate::{Deleter, Unique};
+
+///Read-copy-update cell.
+///
+///Readers obtain cheap snapshot of current value, while writers install replacement.
+///
+///Replaced value is returned to writer (or disposed of with deleter) only once all readers,
+///that could observe it, finish reading.
+///
+///Writers are serialized and wait for readers, therefore it is suited for read-mostly data,
+///like configuration.
+///
+///# Deadlock
+///
+///Writer waits for all readers of previous value, therefore thread must not hold read guard
+///while replacing value.
+///
+///```rust
+///use smart_ptr::{RcuCell, Unique};
+///
+///let mut first = 1u32;
+///let mut second = 2u32;
+///let cell = RcuCell::<u32, ()>::new((&mut first).into());
+///{
+///    let snapshot = cell.read();
+///    assert_eq!(*snapshot, 1);
+///}
+///let old = cell.replace((&mut second).into());
+///assert_eq!(*old, 1);
+///assert_eq!(*cell.read(), 2);
+///```
+pub struct RcuCell<'a, T, D: Deleter> {
+    inner: AtomicPtr<T>,
+    epoch: AtomicUsize,
+    readers: [AtomicUsize; 2],
+    is_writing: AtomicBool,
+    _traits: marker::PhantomData<Unique<'a, T, D>>,
+}
+
+impl<'a, T, D: Deleter> RcuCell<'a, T, D> {
+    #[inline]
+    ///Creates new cell with provided pointer.
+    pub fn new(ptr: Unique<'a, T, D>) -> Self {
+        Self {
+            inner: AtomicPtr::new(ptr.release().as_ptr()),
+            epoch: AtomicUsize::new(0),
+            readers: [AtomicUsize::new(0), AtomicUsize::new(0)],
+            is_writing: AtomicBool::new(false),
+            _traits: marker::PhantomData,
+        }
+    }
+
+    ///Acquires snapshot of current value.
+    pub fn read(&self) -> RcuGuard<'_, T> {
+        let epoch = loop {
+            let epoch = self.epoch.load(Ordering::SeqCst);
+            self.readers[epoch].fetch_add(1, Ordering::SeqCst);
+            //Writer may flip epoch in between, in which case we need to retry
+            //to make sure writer cannot miss us.
+            if self.epoch.load(Ordering::SeqCst) == epoch {
+                break epoch;
+            }
+            self.readers[epoch].fetch_sub(1, Ordering::SeqCst);
+        };
+
+        RcuGuard {
+            value: self.inner.load(Ordering::SeqCst),
+            readers: &self.readers[epoch],
+        }
+    }
+
+    ///Installs new value, returning previous one once all its readers are gone.
+    pub fn replace(&self, ptr: Unique<'a, T, D>) -> Unique<'a, T, D> {
+        while self.is_writing.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed).is_err() {
+            hint::spin_loop();
+        }
+
+        let old = self.inner.swap(ptr.release().as_ptr(), Ordering::SeqCst);
+        let epoch = self.epoch.load(Ordering::SeqCst);
+        self.epoch.store(epoch ^ 1, Ordering::SeqCst);
+        while self.readers[epoch].load(Ordering::SeqCst) != 0 {
+            hint::spin_loop();
+        }
+
+        self.is_writing.store(false, Ordering::Release);
+
+        unsafe {
+            Unique::from_ptr_unchecked(old)
+        }
+    }
+
+    #[inline]
+    ///Installs new value, disposing of previous one once all its readers are gone.
+    pub fn store(&self, ptr: Unique<'a, T, D>) {
+        drop(self.replace(ptr));
+    }
+
+    #[inline]
+    ///Gets mutable reference to the value.
+    pub fn get_mut(&mut self) -> &mut T {
+        unsafe {
+            &mut **self.inner.get_mut()
+        }
+    }
+
+    #[inline]
+    ///Consumes cell, returning stored pointer.
+    pub fn into_inner(mut self) -> Unique<'a, T, D> {
+        let ptr = mem::replace(self.inner.get_mut(), ptr::null_mut());
+        mem::forget(self);
+        unsafe {
+            Unique::from_ptr_unchecked(ptr)
+        }
+    }
+}
+
+impl<'a, T, D: Deleter> Drop for RcuCell<'a, T, D> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            D::delete_with_layout::<T>(*self.inner.get_mut(), core::alloc::Layout::new::<T>());
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, D: Deleter> fmt::Debug for RcuCell<'a, T, D> {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.read(), fmt)
+    }
+}
+
+unsafe impl<'a, T: Send, D: Deleter> Send for RcuCell<'a, T, D> {}
+
+unsafe impl<'a, T: Send + Sync, D: Deleter> Sync for RcuCell<'a, T, D> {}
+
+///Snapshot of `RcuCell` value.
+///
+///Value is guaranteed to be alive as long as guard exists.
+pub struct RcuGuard<'c, T> {
+    value: *const T,
+    readers: &'c AtomicUsize,
+}
+
+impl<'c, T> core::ops::Deref for RcuGuard<'c, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            &*self.value
+        }
+    }
+}
+
+impl<'c, T> Drop for RcuGuard<'c, T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.readers.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl<'c, T: fmt::Debug> fmt::Debug for RcuGuard<'c, T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, fmt)
+    }
+}
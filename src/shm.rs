@@ -0,0 +1,220 @@
+//!POSIX shared memory segment, available on Linux and macOS with `std` feature.
+
+use core::{mem, ptr};
+use core::ffi::{c_int, c_void, CStr};
+
+use std::io;
+use std::ffi::CString;
+
+use crate::Unique;
+
+mod sys {
+    use core::ffi::{c_char, c_int, c_void};
+
+    pub const O_RDWR: c_int = 2;
+    #[cfg(target_os = "linux")]
+    pub const O_CREAT: c_int = 0o100;
+    #[cfg(target_os = "linux")]
+    pub const O_EXCL: c_int = 0o200;
+    #[cfg(target_os = "macos")]
+    pub const O_CREAT: c_int = 0x200;
+    #[cfg(target_os = "macos")]
+    pub const O_EXCL: c_int = 0x800;
+
+    pub const PROT_READ: c_int = 1;
+    pub const PROT_WRITE: c_int = 2;
+    pub const MAP_SHARED: c_int = 1;
+    pub const MAP_FAILED: *mut c_void = !0 as *mut c_void;
+
+    #[cfg_attr(target_os = "linux", link(name = "rt"))]
+    extern "C" {
+        #[cfg(target_os = "linux")]
+        pub fn shm_open(name: *const c_char, oflag: c_int, mode: u32) -> c_int;
+        #[cfg(target_os = "macos")]
+        pub fn shm_open(name: *const c_char, oflag: c_int, ...) -> c_int;
+        pub fn shm_unlink(name: *const c_char) -> c_int;
+    }
+
+    //`off_t` is 32 bit on 32 bit glibc, therefore its 64 bit variants are used instead.
+    #[cfg(not(all(target_os = "linux", target_env = "gnu", target_pointer_width = "32")))]
+    extern "C" {
+        pub fn ftruncate(fd: c_int, len: i64) -> c_int;
+        pub fn mmap(addr: *mut c_void, len: usize, prot: c_int, flags: c_int, fd: c_int, offset: i64) -> *mut c_void;
+    }
+
+    #[cfg(all(target_os = "linux", target_env = "gnu", target_pointer_width = "32"))]
+    extern "C" {
+        #[link_name = "ftruncate64"]
+        pub fn ftruncate(fd: c_int, len: i64) -> c_int;
+        #[link_name = "mmap64"]
+        pub fn mmap(addr: *mut c_void, len: usize, prot: c_int, flags: c_int, fd: c_int, offset: i64) -> *mut c_void;
+    }
+
+    extern "C" {
+        pub fn munmap(addr: *mut c_void, len: usize) -> c_int;
+        pub fn close(fd: c_int) -> c_int;
+    }
+}
+
+///Shared memory segment, created via `shm_open` and mapped into address space via `mmap`.
+///
+///On `Drop` segment is unmapped and its descriptor is closed.
+///Segment's name is unlinked as well, if it is created by this instance, which can be changed via
+///[set_unlink](#method.set_unlink).
+///
+///```rust
+///use smart_ptr::shm::SharedMemory;
+///
+///let name = std::ffi::CString::new(format!("/smart-ptr-doc-{}", std::process::id())).unwrap();
+///let owner = SharedMemory::create(&name, 64).expect("to create segment");
+///let peer = SharedMemory::open(&name, 64).expect("to open segment");
+///
+///unsafe {
+///    *owner.view::<u32>(0).expect("to fit") = 42;
+///    assert_eq!(*peer.view::<u32>(0).expect("to fit"), 42);
+///}
+///```
+pub struct SharedMemory {
+    name: CString,
+    fd: c_int,
+    ptr: ptr::NonNull<u8>,
+    len: usize,
+    unlink: bool,
+}
+
+impl SharedMemory {
+    ///Creates new segment of `len` bytes, filled with zeroes.
+    ///
+    ///Fails if segment with the same name already exists.
+    ///Name should start with `/` and contain no other slashes.
+    pub fn create(name: &CStr, len: usize) -> io::Result<Self> {
+        let fd = unsafe {
+            sys::shm_open(name.as_ptr(), sys::O_RDWR | sys::O_CREAT | sys::O_EXCL, 0o600)
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let result = unsafe {
+            sys::ftruncate(fd, len as i64)
+        };
+        if result != 0 {
+            let error = io::Error::last_os_error();
+            unsafe {
+                sys::shm_unlink(name.as_ptr());
+                sys::close(fd);
+            }
+            return Err(error);
+        }
+
+        Self::map(name, fd, len, true)
+    }
+
+    ///Opens existing segment, mapping first `len` bytes of it.
+    ///
+    ///Segment must be at least `len` bytes long.
+    pub fn open(name: &CStr, len: usize) -> io::Result<Self> {
+        let fd = unsafe {
+            sys::shm_open(name.as_ptr(), sys::O_RDWR, 0)
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Self::map(name, fd, len, false)
+    }
+
+    fn map(name: &CStr, fd: c_int, len: usize, unlink: bool) -> io::Result<Self> {
+        let ptr = unsafe {
+            sys::mmap(ptr::null_mut(), len, sys::PROT_READ | sys::PROT_WRITE, sys::MAP_SHARED, fd, 0)
+        };
+
+        match ptr::NonNull::new(ptr as *mut u8) {
+            Some(ptr) if ptr.as_ptr() as *mut c_void != sys::MAP_FAILED => Ok(Self {
+                name: name.into(),
+                fd,
+                ptr,
+                len,
+                unlink,
+            }),
+            _ => {
+                let error = io::Error::last_os_error();
+                unsafe {
+                    if unlink {
+                        sys::shm_unlink(name.as_ptr());
+                    }
+                    sys::close(fd);
+                }
+                Err(error)
+            }
+        }
+    }
+
+    #[inline(always)]
+    ///Returns name of the segment.
+    pub fn name(&self) -> &CStr {
+        &self.name
+    }
+
+    #[inline(always)]
+    ///Returns file descriptor of the segment.
+    pub fn fd(&self) -> c_int {
+        self.fd
+    }
+
+    #[inline(always)]
+    ///Returns size of the mapping in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    ///Returns whether mapping is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    ///Gets pointer to the start of mapping.
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    ///Sets whether segment's name should be unlinked on `Drop`.
+    pub fn set_unlink(&mut self, unlink: bool) {
+        self.unlink = unlink;
+    }
+
+    #[inline]
+    ///Gets typed view of the segment at `offset` bytes.
+    ///
+    ///Returns `None` if value doesn't fit into mapping or `offset` is not properly aligned for `T`.
+    ///
+    ///View never disposes of memory, but user must guarantee that there are no other views or
+    ///processes accessing the same memory in a way, that violates aliasing rules.
+    pub unsafe fn view<T>(&self, offset: usize) -> Option<Unique<'_, T, ()>> {
+        let end = offset.checked_add(mem::size_of::<T>())?;
+        if end > self.len {
+            return None;
+        }
+
+        Unique::new_aligned(self.ptr.as_ptr().add(offset) as *mut T).ok()
+    }
+}
+
+impl Drop for SharedMemory {
+    fn drop(&mut self) {
+        unsafe {
+            sys::munmap(self.ptr.as_ptr() as *mut c_void, self.len);
+            sys::close(self.fd);
+            if self.unlink {
+                sys::shm_unlink(self.name.as_ptr());
+            }
+        }
+    }
+}
+
+unsafe impl Send for SharedMemory {}
+
+unsafe impl Sync for SharedMemory {}
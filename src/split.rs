@@ -0,0 +1,95 @@
+//!Owned parts of split slice, sharing de-allocation of the original block.
+
+use core::{mem, ptr, marker};
+
+use alloc::sync::Arc;
+
+use crate::{Deleter, StatefulUnique};
+use crate::stateful::StatefulDeleter;
+
+//Original block, which is disposed of once all parts are dropped.
+//
+//Elements are dropped by parts, therefore block is disposed of as slice of `ManuallyDrop`.
+struct Block<T, D: Deleter> {
+    ptr: *mut [mem::ManuallyDrop<T>],
+    _traits: marker::PhantomData<D>,
+}
+
+impl<T, D: Deleter> Drop for Block<T, D> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            D::delete_slice::<mem::ManuallyDrop<T>>(self.ptr as *mut mem::ManuallyDrop<T>, (&*self.ptr).len())
+        }
+    }
+}
+
+unsafe impl<T: Send, D: Deleter> Send for Block<T, D> {}
+
+unsafe impl<T: Send, D: Deleter> Sync for Block<T, D> {}
+
+///Deleter of slice's part, created by [split_at](../unique/struct.Unique.html#method.split_at).
+///
+///It drops elements of its part, while original block is disposed of with deleter `D` once all parts
+///are dropped.
+pub struct SplitDeleter<T, D: Deleter> {
+    block: Arc<Block<T, D>>,
+}
+
+impl<T, D: Deleter> SplitDeleter<T, D> {
+    #[inline]
+    pub(crate) unsafe fn new(ptr: *mut [T]) -> Self {
+        Self {
+            block: Arc::new(Block {
+                ptr: ptr as *mut [mem::ManuallyDrop<T>],
+                _traits: marker::PhantomData,
+            }),
+        }
+    }
+}
+
+impl<T, D: Deleter> Clone for SplitDeleter<T, D> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            block: self.block.clone(),
+        }
+    }
+}
+
+impl<T, D: Deleter> StatefulDeleter<[T]> for SplitDeleter<T, D> {
+    #[inline]
+    unsafe fn delete(self, ptr: *mut [T]) {
+        ptr::drop_in_place(ptr);
+    }
+}
+
+///Part of the slice, created by [split_at](../unique/struct.Unique.html#method.split_at).
+pub type SplitPart<'a, T, D> = StatefulUnique<'a, [T], SplitDeleter<T, D>>;
+
+#[inline]
+//Splits `ptr` into two parts, using the same deleter.
+pub(crate) unsafe fn split<'a, T, D: Deleter>(ptr: *mut [T], mid: usize, deleter: SplitDeleter<T, D>) -> (SplitPart<'a, T, D>, SplitPart<'a, T, D>) {
+    let len = (&*ptr).len();
+    assert!(mid <= len, "mid > len");
+
+    let start = ptr as *mut T;
+    let left = ptr::slice_from_raw_parts_mut(start, mid);
+    let right = ptr::slice_from_raw_parts_mut(start.add(mid), len - mid);
+    (StatefulUnique::from_ptr_unchecked(left, deleter.clone()), StatefulUnique::from_ptr_unchecked(right, deleter))
+}
+
+impl<'a, T, D: Deleter> SplitPart<'a, T, D> {
+    #[inline]
+    ///Splits part further into two parts, sharing the same original block.
+    ///
+    ///# Panics
+    ///
+    ///- If `mid > len`.
+    pub fn split_at(self, mid: usize) -> (Self, Self) {
+        let (ptr, deleter) = self.release();
+        unsafe {
+            split(ptr.as_ptr(), mid, deleter)
+        }
+    }
+}
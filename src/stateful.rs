@@ -0,0 +1,249 @@
+//!Unique pointer with deleter, that carries runtime state.
+
+use core::{mem, fmt, ptr, marker};
+use core::ffi::c_void;
+
+///Describes how to de-allocate pointer, using deleter's own state.
+///
+///Unlike [Deleter](../trait.Deleter.html) it is stored alongside pointer, therefore it can be
+///chosen at runtime.
+pub trait StatefulDeleter<T: ?Sized> {
+    ///This function is called on `Drop`, consuming deleter.
+    unsafe fn delete(self, ptr: *mut T);
+}
+
+impl<T: ?Sized, F: FnOnce(*mut T)> StatefulDeleter<T> for F {
+    #[inline(always)]
+    unsafe fn delete(self, ptr: *mut T) {
+        (self)(ptr)
+    }
+}
+
+impl<T: ?Sized> StatefulDeleter<T> for &dyn crate::DynDeleter {
+    #[inline(always)]
+    unsafe fn delete(self, ptr: *mut T) {
+        self.delete_dyn(ptr as *mut (), core::alloc::Layout::for_value(&*ptr))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> StatefulDeleter<T> for alloc::boxed::Box<dyn crate::DynDeleter> {
+    #[inline(always)]
+    unsafe fn delete(self, ptr: *mut T) {
+        self.delete_dyn(ptr as *mut (), core::alloc::Layout::for_value(&*ptr))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+///Deleter, which invokes C function, provided at runtime.
+///
+///Useful when free function is chosen dynamically (e.g. provided by C library's allocator).
+///
+///```rust
+///use smart_ptr::stateful::{FnDeleter, StatefulUnique};
+///
+///unsafe extern "C" fn reset(ptr: *mut core::ffi::c_void) {
+///    *(ptr as *mut u32) = 0;
+///}
+///
+///let mut value = 1u32;
+///let ptr = unsafe { StatefulUnique::new(&mut value as *mut u32, FnDeleter(reset)) };
+///assert_eq!(*ptr, 1);
+///drop(ptr);
+///assert_eq!(value, 0);
+///```
+pub struct FnDeleter(pub unsafe extern "C" fn(*mut c_void));
+
+impl<T: ?Sized> StatefulDeleter<T> for FnDeleter {
+    #[inline(always)]
+    unsafe fn delete(self, ptr: *mut T) {
+        (self.0)(ptr as *mut c_void)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+///Deleter, which invokes C function with pointer and opaque context.
+///
+///Covers common C convention of `free_fn(ptr, user_data)`.
+///
+///```rust
+///use smart_ptr::stateful::{CtxDeleter, StatefulUnique};
+///use core::ffi::c_void;
+///
+///unsafe extern "C" fn release(ptr: *mut c_void, ctx: *mut c_void) {
+///    *(ctx as *mut u32) += *(ptr as *mut u32);
+///}
+///
+///let mut released = 0u32;
+///let mut value = 2u32;
+///let deleter = CtxDeleter::new(release, &mut released as *mut u32 as *mut c_void);
+///let ptr = unsafe { StatefulUnique::new(&mut value as *mut u32, deleter) };
+///drop(ptr);
+///assert_eq!(released, 2);
+///```
+pub struct CtxDeleter {
+    ///Function, that disposes of pointer.
+    pub free: unsafe extern "C" fn(*mut c_void, *mut c_void),
+    ///Context, passed as second argument of `free`.
+    pub ctx: *mut c_void,
+}
+
+impl CtxDeleter {
+    #[inline(always)]
+    ///Creates new instance.
+    pub const fn new(free: unsafe extern "C" fn(*mut c_void, *mut c_void), ctx: *mut c_void) -> Self {
+        Self {
+            free,
+            ctx,
+        }
+    }
+}
+
+impl<T: ?Sized> StatefulDeleter<T> for CtxDeleter {
+    #[inline(always)]
+    unsafe fn delete(self, ptr: *mut T) {
+        (self.free)(ptr as *mut c_void, self.ctx)
+    }
+}
+
+///Smart pointer, that owns and manages object via its pointer, alongside with its deleter.
+///
+///On `Drop` it disposes of pointer by passing it to deleter.
+pub struct StatefulUnique<'a, T: ?Sized, D: StatefulDeleter<T>> {
+    inner: ptr::NonNull<T>,
+    deleter: mem::ManuallyDrop<D>,
+    _traits: marker::PhantomData<&'a ()>,
+}
+
+impl<'a, T: ?Sized, D: StatefulDeleter<T>> StatefulUnique<'a, T, D> {
+    #[inline]
+    ///Creates new instance from raw pointer and deleter.
+    ///
+    ///# Panics
+    ///
+    ///- If pointer is null
+    pub unsafe fn new(ptr: *mut T, deleter: D) -> Self {
+        assert!(!ptr.is_null());
+
+        Self::from_ptr_unchecked(ptr, deleter)
+    }
+
+    #[inline]
+    ///Creates instance from raw pointer and deleter, checking if pointer is null.
+    ///
+    ///Returns `None` if pointer is null.
+    pub unsafe fn from_ptr(ptr: *mut T, deleter: D) -> Option<Self> {
+        match ptr.is_null() {
+            true => None,
+            false => Some(Self::from_ptr_unchecked(ptr, deleter)),
+        }
+    }
+
+    #[inline]
+    ///Creates instance from raw pointer and deleter, without checking if pointer is null.
+    ///
+    ///User must ensure that pointer is non-null
+    pub unsafe fn from_ptr_unchecked(ptr: *mut T, deleter: D) -> Self {
+        Self {
+            inner: ptr::NonNull::new_unchecked(ptr),
+            deleter: mem::ManuallyDrop::new(deleter),
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer.
+    pub fn get(&self) -> *mut T {
+        self.inner.as_ptr()
+    }
+
+    #[inline(always)]
+    ///Gets reference to underlying data.
+    pub fn as_ref(&self) -> &T {
+        self
+    }
+
+    #[inline(always)]
+    ///Gets mutable reference to underlying data.
+    pub fn as_mut(&mut self) -> &mut T {
+        self
+    }
+
+    #[inline(always)]
+    ///Gets reference to deleter.
+    pub fn deleter(&self) -> &D {
+        &self.deleter
+    }
+
+    #[inline]
+    ///Releases the ownership and returns raw pointer with its deleter, without dropping it.
+    pub fn release(mut self) -> (ptr::NonNull<T>, D) {
+        let result = (self.inner, unsafe { mem::ManuallyDrop::take(&mut self.deleter) });
+        mem::forget(self);
+        result
+    }
+}
+
+impl<'a, T: ?Sized, D: StatefulDeleter<T>> Drop for StatefulUnique<'a, T, D> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            mem::ManuallyDrop::take(&mut self.deleter).delete(self.inner.as_ptr())
+        }
+    }
+}
+
+impl<'a, T: ?Sized, D: StatefulDeleter<T>> core::ops::Deref for StatefulUnique<'a, T, D> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            &*self.inner.as_ptr()
+        }
+    }
+}
+
+impl<'a, T: ?Sized, D: StatefulDeleter<T>> core::ops::DerefMut for StatefulUnique<'a, T, D> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe {
+            &mut *self.inner.as_ptr()
+        }
+    }
+}
+
+impl<'a, T: ?Sized, D: StatefulDeleter<T>> fmt::Pointer for StatefulUnique<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.inner, fmt)
+    }
+}
+
+impl<'a, T: ?Sized + fmt::Debug, D: StatefulDeleter<T>> fmt::Debug for StatefulUnique<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_ref(), fmt)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<'a, T: ?Sized + defmt::Format, D: StatefulDeleter<T>> defmt::Format for StatefulUnique<'a, T, D> {
+    #[inline(always)]
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::Format::format(self.as_ref(), fmt)
+    }
+}
+
+impl<'a, T: ?Sized + fmt::Display, D: StatefulDeleter<T>> fmt::Display for StatefulUnique<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_ref(), fmt)
+    }
+}
+
+impl<'a, T: ?Sized + Unpin, D: StatefulDeleter<T>> Unpin for StatefulUnique<'a, T, D> {}
+
+unsafe impl<'a, T: ?Sized + Send, D: StatefulDeleter<T> + Send> Send for StatefulUnique<'a, T, D> {}
+
+unsafe impl<'a, T: ?Sized + Sync, D: StatefulDeleter<T> + Sync> Sync for StatefulUnique<'a, T, D> {}
@@ -0,0 +1,77 @@
+//!Allocation statistics, enabled by `stats` feature.
+//!
+//!Counters are updated by allocating constructors of the crate and by deleters, that free memory
+//!of global allocator (i.e. `GlobalDeleter` and `DeallocOnly`).
+//!
+//!Note that memory, allocated outside of the crate (e.g. `Box` converted into `Unique`), is only
+//!accounted on de-allocation.
+//!
+//!```rust
+//!use smart_ptr::unique::Global;
+//!
+//!let before = smart_ptr::stats();
+//!let ptr = Global::boxed(1u64);
+//!let after = smart_ptr::stats();
+//!assert!(after.allocations > before.allocations);
+//!drop(ptr);
+//!assert!(smart_ptr::stats().deallocations > before.deallocations);
+//!```
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static DEALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static DEALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+///Snapshot of allocation counters.
+pub struct Stats {
+    ///Total number of allocations.
+    pub allocations: usize,
+    ///Total number of allocated bytes.
+    pub allocated_bytes: usize,
+    ///Total number of de-allocations.
+    pub deallocations: usize,
+    ///Total number of de-allocated bytes.
+    pub deallocated_bytes: usize,
+}
+
+impl Stats {
+    #[inline(always)]
+    ///Returns number of live allocations.
+    pub fn live(&self) -> usize {
+        self.allocations.saturating_sub(self.deallocations)
+    }
+
+    #[inline(always)]
+    ///Returns number of bytes in live allocations.
+    pub fn live_bytes(&self) -> usize {
+        self.allocated_bytes.saturating_sub(self.deallocated_bytes)
+    }
+}
+
+#[inline]
+pub(crate) fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+}
+
+#[inline]
+pub(crate) fn record_dealloc(size: usize) {
+    DEALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    DEALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+}
+
+///Returns current allocation statistics.
+///
+///Counters are updated independently, so snapshot taken concurrently with allocations may be
+///slightly inconsistent.
+pub fn stats() -> Stats {
+    Stats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+        deallocations: DEALLOCATIONS.load(Ordering::Relaxed),
+        deallocated_bytes: DEALLOCATED_BYTES.load(Ordering::Relaxed),
+    }
+}
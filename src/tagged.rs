@@ -0,0 +1,349 @@
+//!Generational pointers for ABA protection in lock-free structures.
+//!
+//!Pointer is paired with generation tag, which is incremented on every replacement, so that
+//!compare-and-swap fails if the same address is re-used in between.
+
+use core::fmt;
+#[cfg(target_pointer_width = "64")]
+use core::marker;
+#[cfg(target_pointer_width = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(target_pointer_width = "64")]
+const ADDRESS_BITS: u32 = 48;
+#[cfg(target_pointer_width = "64")]
+const ADDRESS_MASK: u64 = (1 << ADDRESS_BITS) - 1;
+
+#[cfg(target_pointer_width = "64")]
+///Pointer with 16-bit generation tag, packed into single 64-bit word.
+///
+///Tag is stored in upper 16 bits of the address, which are unused by user space pointers, as long
+///as virtual address space is limited to 48 bits (e.g. common `x86_64` and `aarch64` configurations).
+///Platforms with bigger address space (e.g. 5-level paging on `x86_64` or 52-bit `aarch64`) may
+///hand out addresses, that cannot be tagged, therefore [try_new](#method.try_new) should be used to
+///detect them and fall back to double word [GenPtr](struct.GenPtr.html).
+///
+///```rust
+///use smart_ptr::tagged::TaggedPtr;
+///
+///let mut value = 1u32;
+///let ptr = TaggedPtr::new(&mut value as *mut u32, 0);
+///let next = ptr.next(&mut value as *mut u32);
+///assert_eq!(next.ptr(), ptr.ptr());
+///assert_eq!(next.tag(), 1);
+///assert_ne!(next, ptr);
+///```
+pub struct TaggedPtr<T> {
+    bits: u64,
+    _traits: marker::PhantomData<*mut T>,
+}
+
+#[cfg(target_pointer_width = "64")]
+impl<T> TaggedPtr<T> {
+    #[inline]
+    ///Creates new instance.
+    ///
+    ///# Panics
+    ///
+    ///- If address doesn't fit into 48 bits.
+    pub fn new(ptr: *mut T, tag: u16) -> Self {
+        match Self::try_new(ptr, tag) {
+            Some(result) => result,
+            None => panic!("Address doesn't fit into 48 bits"),
+        }
+    }
+
+    #[inline]
+    ///Creates new instance, if address fits into 48 bits.
+    ///
+    ///Returns `None` otherwise, in which case user must fall back to [GenPtr](struct.GenPtr.html).
+    ///
+    ///```rust
+    ///use smart_ptr::tagged::TaggedPtr;
+    ///
+    ///let mut value = 1u32;
+    ///assert!(TaggedPtr::try_new(&mut value as *mut u32, 0).is_some());
+    ///assert!(TaggedPtr::try_new((1usize << 52) as *mut u32, 0).is_none());
+    ///```
+    pub fn try_new(ptr: *mut T, tag: u16) -> Option<Self> {
+        let address = ptr as u64;
+        match address & !ADDRESS_MASK {
+            0 => Some(Self::from_bits(address | ((tag as u64) << ADDRESS_BITS))),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    ///Creates instance from packed representation.
+    pub const fn from_bits(bits: u64) -> Self {
+        Self {
+            bits,
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns packed representation.
+    pub const fn into_bits(self) -> u64 {
+        self.bits
+    }
+
+    #[inline(always)]
+    ///Returns pointer.
+    pub fn ptr(self) -> *mut T {
+        (self.bits & ADDRESS_MASK) as usize as *mut T
+    }
+
+    #[inline(always)]
+    ///Returns generation tag.
+    pub const fn tag(self) -> u16 {
+        (self.bits >> ADDRESS_BITS) as u16
+    }
+
+    #[inline]
+    ///Returns `ptr` with tag of the next generation.
+    ///
+    ///Tag wraps around on overflow.
+    ///
+    ///# Panics
+    ///
+    ///- If address doesn't fit into 48 bits.
+    pub fn next(self, ptr: *mut T) -> Self {
+        Self::new(ptr, self.tag().wrapping_add(1))
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl<T> Clone for TaggedPtr<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl<T> Copy for TaggedPtr<T> {}
+
+#[cfg(target_pointer_width = "64")]
+impl<T> PartialEq for TaggedPtr<T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl<T> Eq for TaggedPtr<T> {}
+
+#[cfg(target_pointer_width = "64")]
+impl<T> fmt::Debug for TaggedPtr<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("TaggedPtr").field("ptr", &self.ptr()).field("tag", &self.tag()).finish()
+    }
+}
+
+#[cfg(all(feature = "defmt", target_pointer_width = "64"))]
+impl<T> defmt::Format for TaggedPtr<T> {
+    #[inline(always)]
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(fmt, "{=str}@{=usize:#x}#{=u16}", core::any::type_name::<T>(), self.ptr() as *const u8 as usize, self.tag())
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+///Atomic [TaggedPtr](struct.TaggedPtr.html).
+///
+///It doesn't own pointer, therefore ownership is to be transferred via
+///[Unique::release](../unique/struct.Unique.html#method.release) and
+///[Unique::from_ptr](../unique/struct.Unique.html#method.from_ptr).
+///
+///Storing pointer, which address doesn't fit into 48 bits, panics, as it cannot be tagged.
+///
+///```rust
+///use smart_ptr::tagged::AtomicTaggedPtr;
+///
+///let mut first = 1u32;
+///let mut second = 2u32;
+///let atomic = AtomicTaggedPtr::new(&mut first as *mut u32);
+///let stale = atomic.load();
+///
+///assert!(atomic.compare_exchange(stale, &mut second as *mut u32).is_ok());
+///assert!(atomic.compare_exchange(atomic.load(), &mut first as *mut u32).is_ok());
+///
+/////Address is the same, but generation differs
+///assert_eq!(atomic.load().ptr(), stale.ptr());
+///assert!(atomic.compare_exchange(stale, &mut second as *mut u32).is_err());
+///```
+pub struct AtomicTaggedPtr<T> {
+    inner: AtomicU64,
+    _traits: marker::PhantomData<*mut T>,
+}
+
+#[cfg(target_pointer_width = "64")]
+impl<T> AtomicTaggedPtr<T> {
+    #[inline]
+    ///Creates new instance with initial generation.
+    pub fn new(ptr: *mut T) -> Self {
+        Self {
+            inner: AtomicU64::new(TaggedPtr::new(ptr, 0).into_bits()),
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Loads current pointer with its generation.
+    pub fn load(&self) -> TaggedPtr<T> {
+        TaggedPtr::from_bits(self.inner.load(Ordering::Acquire))
+    }
+
+    #[inline]
+    ///Stores `ptr` with the next generation, returning previous pointer.
+    pub fn swap(&self, ptr: *mut T) -> TaggedPtr<T> {
+        let mut current = self.load();
+        loop {
+            match self.compare_exchange(current, ptr) {
+                Ok(old) => break old,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    #[inline]
+    ///Stores `ptr` with the next generation, if current pointer and its generation are the same as `current`.
+    ///
+    ///On success returns previous pointer, otherwise returns actual one.
+    pub fn compare_exchange(&self, current: TaggedPtr<T>, ptr: *mut T) -> Result<TaggedPtr<T>, TaggedPtr<T>> {
+        let new = current.next(ptr);
+        match self.inner.compare_exchange(current.into_bits(), new.into_bits(), Ordering::AcqRel, Ordering::Acquire) {
+            Ok(old) => Ok(TaggedPtr::from_bits(old)),
+            Err(actual) => Err(TaggedPtr::from_bits(actual)),
+        }
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl<T> fmt::Debug for AtomicTaggedPtr<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(), fmt)
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+unsafe impl<T: Send> Send for AtomicTaggedPtr<T> {}
+
+#[cfg(target_pointer_width = "64")]
+unsafe impl<T: Send> Sync for AtomicTaggedPtr<T> {}
+
+#[repr(C)]
+#[cfg_attr(target_pointer_width = "64", repr(align(16)))]
+#[cfg_attr(target_pointer_width = "32", repr(align(8)))]
+///Pointer with full word generation, occupying two words.
+///
+///It is aligned to its size, therefore it can be used with double word compare-and-swap
+///(e.g. `cmpxchg16b`), via its [into_bits](#method.into_bits) representation.
+///
+///```rust
+///use smart_ptr::tagged::GenPtr;
+///
+///let mut value = 1u32;
+///let ptr = GenPtr::new(&mut value as *mut u32, usize::MAX);
+///let next = ptr.next(ptr.ptr());
+///assert_eq!(next.generation(), 0);
+///assert_eq!(GenPtr::from_bits(next.into_bits()), next);
+///```
+pub struct GenPtr<T> {
+    ptr: *mut T,
+    generation: usize,
+}
+
+impl<T> GenPtr<T> {
+    #[inline(always)]
+    ///Creates new instance.
+    pub const fn new(ptr: *mut T, generation: usize) -> Self {
+        Self {
+            ptr,
+            generation,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns pointer.
+    pub const fn ptr(self) -> *mut T {
+        self.ptr
+    }
+
+    #[inline(always)]
+    ///Returns generation.
+    pub const fn generation(self) -> usize {
+        self.generation
+    }
+
+    #[inline(always)]
+    ///Returns `ptr` with the next generation.
+    ///
+    ///Generation wraps around on overflow.
+    pub const fn next(self, ptr: *mut T) -> Self {
+        Self::new(ptr, self.generation.wrapping_add(1))
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[inline(always)]
+    ///Returns double word representation, with pointer in lower word.
+    pub fn into_bits(self) -> u128 {
+        (self.ptr as usize as u128) | ((self.generation as u128) << 64)
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[inline(always)]
+    ///Creates instance from double word representation.
+    pub fn from_bits(bits: u128) -> Self {
+        Self::new(bits as u64 as usize as *mut T, (bits >> 64) as usize)
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[inline(always)]
+    ///Returns double word representation, with pointer in lower word.
+    pub fn into_bits(self) -> u64 {
+        (self.ptr as usize as u64) | ((self.generation as u64) << 32)
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[inline(always)]
+    ///Creates instance from double word representation.
+    pub fn from_bits(bits: u64) -> Self {
+        Self::new(bits as u32 as usize as *mut T, (bits >> 32) as usize)
+    }
+}
+
+impl<T> Clone for GenPtr<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GenPtr<T> {}
+
+impl<T> PartialEq for GenPtr<T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr && self.generation == other.generation
+    }
+}
+
+impl<T> Eq for GenPtr<T> {}
+
+impl<T> fmt::Debug for GenPtr<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("GenPtr").field("ptr", &self.ptr).field("generation", &self.generation).finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for GenPtr<T> {
+    #[inline(always)]
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(fmt, "{=str}@{=usize:#x}#{=usize}", core::any::type_name::<T>(), self.ptr as *const u8 as usize, self.generation)
+    }
+}
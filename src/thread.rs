@@ -0,0 +1,251 @@
+//!Thread safety markers for pointers
+
+use core::{fmt, ptr, marker};
+
+use crate::{Deleter, Unique};
+
+#[repr(transparent)]
+///Wrapper, that asserts pointer can be sent to another thread, regardless of pointee.
+///
+///FFI pointees are usually opaque types (e.g. `c_void`), which are not `Send`, even if C library
+///is documented as thread-safe.
+///
+///```rust
+///use smart_ptr::thread::AssertSend;
+///use smart_ptr::Unique;
+///
+///static mut VALUE: u8 = 0;
+///
+///let ptr = unsafe { Unique::<core::ffi::c_void, ()>::new(core::ptr::addr_of_mut!(VALUE) as *mut _) };
+///let ptr = unsafe { AssertSend::new(ptr) };
+///std::thread::spawn(move || {
+///    let ptr = ptr.into_inner();
+///    assert!(!ptr.get().is_null());
+///}).join().unwrap();
+///```
+pub struct AssertSend<P>(P);
+
+impl<P> AssertSend<P> {
+    #[inline(always)]
+    ///Wraps pointer.
+    ///
+    ///User must guarantee that pointee can be accessed and disposed of from any thread.
+    pub const unsafe fn new(ptr: P) -> Self {
+        Self(ptr)
+    }
+
+    #[inline(always)]
+    ///Returns wrapped pointer.
+    pub fn into_inner(self) -> P {
+        self.0
+    }
+}
+
+impl<P> core::ops::Deref for AssertSend<P> {
+    type Target = P;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<P> core::ops::DerefMut for AssertSend<P> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<P: fmt::Debug> fmt::Debug for AssertSend<P> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fmt)
+    }
+}
+
+unsafe impl<P> Send for AssertSend<P> {}
+
+#[repr(transparent)]
+///Wrapper, that asserts pointer can be shared between threads, regardless of pointee.
+///
+///FFI pointees are usually opaque types (e.g. `c_void`), which are not `Sync`, even if C library
+///is documented as thread-safe.
+pub struct AssertSync<P>(P);
+
+impl<P> AssertSync<P> {
+    #[inline(always)]
+    ///Wraps pointer.
+    ///
+    ///User must guarantee that pointee can be accessed from multiple threads simultaneously.
+    pub const unsafe fn new(ptr: P) -> Self {
+        Self(ptr)
+    }
+
+    #[inline(always)]
+    ///Returns wrapped pointer.
+    pub fn into_inner(self) -> P {
+        self.0
+    }
+}
+
+impl<P> core::ops::Deref for AssertSync<P> {
+    type Target = P;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<P> core::ops::DerefMut for AssertSync<P> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<P: fmt::Debug> fmt::Debug for AssertSync<P> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fmt)
+    }
+}
+
+unsafe impl<P> Sync for AssertSync<P> {}
+
+#[repr(transparent)]
+///Unique pointer, that is bound to its creating thread.
+///
+///It is neither `Send` nor `Sync`, regardless of pointee, which is required by handles, that must
+///only be used and disposed of on the same thread (e.g. GUI or some GPU objects).
+///
+///```rust,compile_fail
+///use smart_ptr::thread::LocalUnique;
+///
+///static mut VALUE: u32 = 0;
+///
+///let ptr = unsafe { LocalUnique::<u32, ()>::new(core::ptr::addr_of_mut!(VALUE)) };
+///std::thread::spawn(move || {
+///    drop(ptr);
+///});
+///```
+pub struct LocalUnique<'a, T: ?Sized, D: Deleter> {
+    inner: Unique<'a, T, D>,
+    _local: marker::PhantomData<*mut ()>,
+}
+
+impl<'a, T: ?Sized, D: Deleter> LocalUnique<'a, T, D> {
+    #[inline]
+    ///Creates new instance from raw pointer.
+    ///
+    ///# Panics
+    ///
+    ///- If pointer is null
+    pub unsafe fn new(ptr: *mut T) -> Self {
+        Unique::new(ptr).into()
+    }
+
+    #[inline]
+    ///Creates instance from raw pointer, checking if pointer is null.
+    ///
+    ///Returns `None` if pointer is null.
+    pub unsafe fn from_ptr(ptr: *mut T) -> Option<Self> {
+        Unique::from_ptr(ptr).map(Into::into)
+    }
+
+    #[inline]
+    ///Creates instance from raw pointer, without checking if pointer is null.
+    ///
+    ///User must ensure that pointer is non-null
+    pub unsafe fn from_ptr_unchecked(ptr: *mut T) -> Self {
+        Unique::from_ptr_unchecked(ptr).into()
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer.
+    pub fn get(&self) -> *mut T {
+        self.inner.get()
+    }
+
+    #[inline(always)]
+    ///Gets reference to underlying data.
+    pub fn as_ref(&self) -> &T {
+        &self.inner
+    }
+
+    #[inline(always)]
+    ///Gets mutable reference to underlying data.
+    pub fn as_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    #[inline(always)]
+    ///Converts into `Unique`, which is no longer bound to current thread.
+    ///
+    ///User must guarantee that pointer is only accessed and disposed of on the current thread.
+    pub unsafe fn into_unique(self) -> Unique<'a, T, D> {
+        self.inner
+    }
+
+    #[inline]
+    ///Releases the ownership and returns raw pointer, without dropping it.
+    pub fn release(self) -> ptr::NonNull<T> {
+        self.inner.release()
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> From<Unique<'a, T, D>> for LocalUnique<'a, T, D> {
+    #[inline(always)]
+    fn from(ptr: Unique<'a, T, D>) -> Self {
+        Self {
+            inner: ptr,
+            _local: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> core::ops::Deref for LocalUnique<'a, T, D> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> core::ops::DerefMut for LocalUnique<'a, T, D> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut()
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> fmt::Pointer for LocalUnique<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.inner, fmt)
+    }
+}
+
+impl<'a, T: ?Sized + fmt::Debug, D: Deleter> fmt::Debug for LocalUnique<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_ref(), fmt)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<'a, T: ?Sized + defmt::Format, D: Deleter> defmt::Format for LocalUnique<'a, T, D> {
+    #[inline(always)]
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::Format::format(self.as_ref(), fmt)
+    }
+}
+
+impl<'a, T: ?Sized + fmt::Display, D: Deleter> fmt::Display for LocalUnique<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_ref(), fmt)
+    }
+}
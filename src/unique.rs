@@ -4,6 +4,30 @@ use core::{mem, fmt, ptr, marker};
 
 use crate::Deleter;
 
+#[cfg(feature = "debug-guards")]
+//Address, written into pointer once it is released or deleted.
+const POISON: usize = usize::MAX - 0xfff;
+
+///Alias to `Unique`, which owns its pointer without borrowing anything.
+///
+///Allows to store pointer in structs without lifetime parameter.
+///
+///```rust
+///use smart_ptr::Owned;
+///
+///pub struct Handle {
+///    ptr: Owned<u32, ()>,
+///}
+///
+///static mut VALUE: u32 = 1;
+///
+///let handle = Handle {
+///    ptr: unsafe { Owned::new(core::ptr::addr_of_mut!(VALUE)) },
+///};
+///assert_eq!(*handle.ptr, 1);
+///```
+pub type Owned<T, D> = Unique<'static, T, D>;
+
 #[cfg(feature = "alloc")]
 ///Alias to `Unique` with `GlobalDeleter` as second type parameter
 pub type Global<T> = Unique<'static, T, crate::GlobalDeleter>;
@@ -12,8 +36,291 @@ pub type Global<T> = Unique<'static, T, crate::GlobalDeleter>;
 impl<T> Global<T> {
     #[inline]
     ///Creates new instance using global allocator
+    ///
+    ///Zero sized values are not allocated.
     pub fn boxed(val: T) -> Self {
-        alloc::boxed::Box::new(val).into()
+        unsafe {
+            Self::from_ptr_unchecked(crate::boxed_raw(val))
+        }
+    }
+
+    #[inline]
+    ///Creates new instance using global allocator, returning `Error::AllocFailed` if allocation fails.
+    ///
+    ///```rust
+    ///use smart_ptr::unique::Global;
+    ///
+    ///let ptr = Global::try_boxed(1u32).expect("to allocate");
+    ///assert_eq!(*ptr, 1);
+    ///```
+    pub fn try_boxed(val: T) -> Result<Self, crate::Error> {
+        let ptr = crate::try_boxed_raw(val)?;
+        unsafe {
+            Ok(Self::from_ptr_unchecked(ptr))
+        }
+    }
+
+    #[inline]
+    ///Takes ownership of zero sized value, without touching allocator.
+    ///
+    ///Value's destructor is still invoked on `Drop`. Size of `T` is checked at compile time.
+    ///
+    ///```rust
+    ///use smart_ptr::unique::Global;
+    ///
+    ///struct Guard;
+    ///
+    ///let guard = Global::zst(Guard);
+    ///assert_eq!(guard.get() as usize, core::mem::align_of::<Guard>());
+    ///```
+    ///
+    ///```compile_fail
+    ///use smart_ptr::unique::Global;
+    ///
+    ///let value = Global::zst(1u8);
+    ///```
+    pub fn zst(val: T) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = AssertZeroSized::<T>::OK;
+
+        Self::boxed(val)
+    }
+
+    #[inline]
+    ///Creates new pinned instance using global allocator
+    pub fn pin(val: T) -> core::pin::Pin<Self> {
+        Self::boxed(val).into()
+    }
+
+    #[inline]
+    ///Creates new uninitialized instance using global allocator
+    ///
+    ///Use [write](struct.Unique.html#method.write) to initialize it.
+    pub fn new_uninit() -> Global<mem::MaybeUninit<T>> {
+        Global::boxed(mem::MaybeUninit::uninit())
+    }
+
+    #[inline]
+    ///Fallible version of [new_uninit](#method.new_uninit).
+    pub fn try_new_uninit() -> Result<Global<mem::MaybeUninit<T>>, crate::Error> {
+        Global::try_boxed(mem::MaybeUninit::uninit())
+    }
+
+    #[inline]
+    ///Creates new uninitialized instance using global allocator, with memory filled by zero bytes.
+    ///
+    ///Memory is requested via `alloc_zeroed`, which is cheaper than zeroing it manually.
+    ///
+    ///```rust
+    ///use smart_ptr::unique::Global;
+    ///
+    ///let ptr = Global::<[u8; 4096]>::new_zeroed();
+    ///let ptr = unsafe { ptr.assume_init() };
+    ///assert!(ptr.iter().all(|byte| *byte == 0));
+    ///```
+    pub fn new_zeroed() -> Global<mem::MaybeUninit<T>> {
+        unsafe {
+            Global::from_ptr_unchecked(crate::alloc_array::<mem::MaybeUninit<T>>(1, true))
+        }
+    }
+
+    #[inline]
+    ///Fallible version of [new_zeroed](#method.new_zeroed).
+    pub fn try_new_zeroed() -> Result<Global<mem::MaybeUninit<T>>, crate::Error> {
+        let ptr = crate::try_alloc_array::<mem::MaybeUninit<T>>(1, true)?;
+        unsafe {
+            Ok(Global::from_ptr_unchecked(ptr))
+        }
+    }
+
+    #[inline]
+    ///Creates copy of the value, returning `Error::AllocFailed` if allocation fails.
+    pub fn try_clone(&self) -> Result<Self, crate::Error> where T: Clone {
+        Self::try_boxed(self.as_ref().clone())
+    }
+
+    #[inline]
+    ///Creates new instance using global allocator, with alignment of at least `align`.
+    ///
+    ///Useful for buffers, which require bigger alignment than their type (e.g. SIMD or DMA).
+    ///
+    ///# Panics
+    ///
+    ///- If `align` is not power of two.
+    ///
+    ///```rust
+    ///use smart_ptr::unique::Global;
+    ///
+    ///let buffer = Global::boxed_aligned([0u8; 256], 64);
+    ///assert_eq!(buffer.get() as usize % 64, 0);
+    ///```
+    pub fn boxed_aligned(val: T, align: usize) -> Unique<'static, T, crate::AlignedDeleter> {
+        unsafe {
+            Unique::from_ptr_unchecked(crate::aligned::boxed_raw(val, align))
+        }
+    }
+
+    #[inline]
+    ///Fallible version of [boxed_aligned](#method.boxed_aligned).
+    ///
+    ///# Panics
+    ///
+    ///- If `align` is not power of two.
+    pub fn try_boxed_aligned(val: T, align: usize) -> Result<Unique<'static, T, crate::AlignedDeleter>, crate::Error> {
+        let ptr = crate::aligned::try_boxed_raw(val, align)?;
+        unsafe {
+            Ok(Unique::from_ptr_unchecked(ptr))
+        }
+    }
+
+    ///Creates new instance using allocator `A`.
+    ///
+    ///Zero sized values are not allocated.
+    ///
+    ///```rust
+    ///use smart_ptr::unique::Global;
+    ///
+    ///#[derive(Default)]
+    ///struct SystemAlloc;
+    ///
+    ///unsafe impl core::alloc::GlobalAlloc for SystemAlloc {
+    ///    unsafe fn alloc(&self, layout: core::alloc::Layout) -> *mut u8 {
+    ///        std::alloc::System.alloc(layout)
+    ///    }
+    ///
+    ///    unsafe fn dealloc(&self, ptr: *mut u8, layout: core::alloc::Layout) {
+    ///        std::alloc::System.dealloc(ptr, layout)
+    ///    }
+    ///}
+    ///
+    ///let ptr = Global::boxed_with::<SystemAlloc>(String::from("system"));
+    ///assert_eq!(*ptr, "system");
+    ///```
+    pub fn boxed_with<A: core::alloc::GlobalAlloc + Default>(val: T) -> Unique<'static, T, crate::AllocDeleter<A>> {
+        match Self::try_boxed_with::<A>(val) {
+            Ok(ptr) => ptr,
+            Err(_) => alloc::alloc::handle_alloc_error(core::alloc::Layout::new::<T>()),
+        }
+    }
+
+    ///Fallible version of [boxed_with](#method.boxed_with).
+    pub fn try_boxed_with<A: core::alloc::GlobalAlloc + Default>(val: T) -> Result<Unique<'static, T, crate::AllocDeleter<A>>, crate::Error> {
+        let layout = core::alloc::Layout::new::<T>();
+        let ptr = match layout.size() {
+            0 => ptr::NonNull::<T>::dangling().as_ptr(),
+            _ => unsafe {
+                let ptr = A::default().alloc(layout);
+                if ptr.is_null() {
+                    return Err(crate::Error::AllocFailed);
+                }
+                ptr as *mut T
+            },
+        };
+
+        unsafe {
+            ptr.write(val);
+            Ok(Unique::from_ptr_unchecked(ptr))
+        }
+    }
+
+    ///Replaces value with `val` of another type, re-using existing allocation.
+    ///
+    ///Old value is dropped. If layout of `U` matches layout of `T`, memory is re-used as it is,
+    ///otherwise it is re-allocated, if alignment is the same, or allocated anew.
+    ///
+    ///```rust
+    ///use smart_ptr::unique::Global;
+    ///
+    ///let request = Global::boxed(1u64);
+    ///let address = request.get() as usize;
+    ///let request = request.recycle(2i64);
+    ///assert_eq!(request.get() as usize, address);
+    ///assert_eq!(*request, 2);
+    ///```
+    pub fn recycle<U>(self, val: U) -> Global<U> {
+        let old = core::alloc::Layout::new::<T>();
+        let new = core::alloc::Layout::new::<U>();
+        //Zero sized values are never allocated
+        if old.align() != new.align() || old.size() == 0 || new.size() == 0 {
+            drop(self);
+            return Global::boxed(val);
+        }
+
+        let ptr = self.release().as_ptr();
+        unsafe {
+            ptr::drop_in_place(ptr);
+
+            let ptr = match old.size() == new.size() {
+                true => ptr as *mut U,
+                false => {
+                    let result = alloc::alloc::realloc(ptr as *mut u8, old, new.size());
+                    if result.is_null() {
+                        alloc::alloc::handle_alloc_error(new);
+                    }
+                    #[cfg(feature = "stats")]
+                    {
+                        crate::stats::record_dealloc(old.size());
+                        crate::stats::record_alloc(new.size());
+                    }
+                    result as *mut U
+                }
+            };
+
+            ptr.write(val);
+            Global::from_ptr_unchecked(ptr)
+        }
+    }
+
+    #[inline]
+    ///Creates new instance using global allocator, starting on cache line boundary and padded to
+    ///cache line size.
+    ///
+    ///Suitable for per-thread state, to avoid false sharing with adjacent allocations.
+    ///
+    ///```rust
+    ///use smart_ptr::unique::Global;
+    ///use smart_ptr::aligned::CachePadded;
+    ///
+    ///let counter = Global::boxed_cache_aligned(0u64);
+    ///assert_eq!(counter.get() as usize % core::mem::align_of::<CachePadded<u64>>(), 0);
+    ///assert_eq!(**counter, 0);
+    ///```
+    pub fn boxed_cache_aligned(val: T) -> Global<crate::aligned::CachePadded<T>> {
+        Global::boxed(crate::aligned::CachePadded::new(val))
+    }
+
+    #[inline]
+    ///Creates new instance using global allocator, occupying whole memory pages.
+    ///
+    ///Allocation starts on page boundary and its size is rounded up to multiple of page size,
+    ///which makes it suitable for `mprotect` or kernel interfaces, that require page aligned memory.
+    ///
+    ///# Panics
+    ///
+    ///- If alignment of `T` is greater than page size.
+    pub fn boxed_pages(val: T) -> Unique<'static, T, crate::aligned::PageDeleter> {
+        assert!(mem::align_of::<T>() <= crate::aligned::page_size());
+
+        let ptr = crate::aligned::alloc_pages(mem::size_of::<T>(), false) as *mut T;
+        unsafe {
+            ptr.write(val);
+            Unique::from_ptr_unchecked(ptr)
+        }
+    }
+
+    ///Fallible version of [boxed_pages](#method.boxed_pages).
+    ///
+    ///# Panics
+    ///
+    ///- If alignment of `T` is greater than page size.
+    pub fn try_boxed_pages(val: T) -> Result<Unique<'static, T, crate::aligned::PageDeleter>, crate::Error> {
+        assert!(mem::align_of::<T>() <= crate::aligned::page_size());
+
+        let ptr = crate::aligned::try_alloc_pages(mem::size_of::<T>(), false)? as *mut T;
+        unsafe {
+            ptr.write(val);
+            Ok(Unique::from_ptr_unchecked(ptr))
+        }
     }
 }
 
@@ -21,12 +328,380 @@ impl<T> Global<T> {
 impl<T: ?Sized> Global<T> {
     #[inline]
     ///Converts ptr to box
+    ///
+    ///Note that neither `From` nor `Into` can be implemented for `Box`, due to it being fundamental type.
     pub fn into_boxed(self) -> alloc::boxed::Box<T> {
         let ptr = self.release().as_ptr();
         unsafe {
             alloc::boxed::Box::from_raw(ptr)
         }
     }
+
+    #[inline]
+    ///Converts ptr to `Rc`, moving value into its allocation.
+    pub fn into_rc(self) -> alloc::rc::Rc<T> {
+        self.into_boxed().into()
+    }
+
+    #[inline]
+    ///Converts ptr to `Arc`, moving value into its allocation.
+    pub fn into_arc(self) -> alloc::sync::Arc<T> {
+        self.into_boxed().into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Global<[T]> {
+    #[inline]
+    ///Creates new instance from vector's elements, shrinking its allocation to fit.
+    pub fn from_vec(vec: alloc::vec::Vec<T>) -> Self {
+        vec.into_boxed_slice().into()
+    }
+
+    #[inline]
+    ///Creates new slice of `len` elements, initializing each of them in place with `init`.
+    ///
+    ///Function receives index of the element.
+    pub fn boxed_slice<F: FnMut(usize) -> T>(len: usize, init: F) -> Self {
+        let ptr = crate::init_array(len, init);
+        unsafe {
+            Self::from_ptr_unchecked(ptr::slice_from_raw_parts_mut(ptr, len))
+        }
+    }
+
+    #[inline]
+    ///Fallible version of [boxed_slice](#method.boxed_slice).
+    ///
+    ///```rust
+    ///use smart_ptr::{Error, unique::Global};
+    ///
+    ///assert_eq!(Global::<[u64]>::try_boxed_slice(usize::MAX, |_| 0).unwrap_err(), Error::AllocFailed);
+    ///```
+    pub fn try_boxed_slice<F: FnMut(usize) -> T>(len: usize, init: F) -> Result<Self, crate::Error> {
+        let ptr = crate::try_init_array(len, init)?;
+        unsafe {
+            Ok(Self::from_ptr_unchecked(ptr::slice_from_raw_parts_mut(ptr, len)))
+        }
+    }
+
+    #[inline]
+    ///Creates copy of the slice, returning `Error::AllocFailed` if allocation fails.
+    pub fn try_clone(&self) -> Result<Self, crate::Error> where T: Clone {
+        let src = self.as_ref();
+        Self::try_boxed_slice(src.len(), |idx| src[idx].clone())
+    }
+
+    #[inline]
+    ///Creates new slice of `len` uninitialized elements.
+    ///
+    ///Use [assume_init](struct.Unique.html#method.assume_init-1) once all elements are initialized.
+    ///
+    ///```rust
+    ///use smart_ptr::unique::Global;
+    ///
+    ///let mut buffer = Global::<[u32]>::new_uninit_slice(3);
+    ///for (idx, elem) in buffer.iter_mut().enumerate() {
+    ///    elem.write(idx as u32);
+    ///}
+    ///let buffer = unsafe { buffer.assume_init() };
+    ///assert_eq!(*buffer, [0, 1, 2]);
+    ///```
+    pub fn new_uninit_slice(len: usize) -> Global<[mem::MaybeUninit<T>]> {
+        let ptr = crate::alloc_array::<mem::MaybeUninit<T>>(len, false);
+        unsafe {
+            Global::from_ptr_unchecked(ptr::slice_from_raw_parts_mut(ptr, len))
+        }
+    }
+
+    #[inline]
+    ///Fallible version of [new_uninit_slice](#method.new_uninit_slice).
+    pub fn try_new_uninit_slice(len: usize) -> Result<Global<[mem::MaybeUninit<T>]>, crate::Error> {
+        let ptr = crate::try_alloc_array::<mem::MaybeUninit<T>>(len, false)?;
+        unsafe {
+            Ok(Global::from_ptr_unchecked(ptr::slice_from_raw_parts_mut(ptr, len)))
+        }
+    }
+
+    #[inline]
+    ///Creates new slice of `len` uninitialized elements, occupying whole memory pages, filled by zero bytes.
+    ///
+    ///Allocation starts on page boundary and its size is rounded up to multiple of page size.
+    ///
+    ///```rust
+    ///use smart_ptr::unique::Global;
+    ///use smart_ptr::aligned::page_size;
+    ///
+    ///let buffer = Global::<[u8]>::new_zeroed_pages(100);
+    ///assert_eq!(buffer.len(), 100);
+    ///assert_eq!(buffer.get() as *mut u8 as usize % page_size(), 0);
+    ///```
+    ///
+    ///# Panics
+    ///
+    ///- If alignment of `T` is greater than page size.
+    pub fn new_zeroed_pages(len: usize) -> Unique<'static, [mem::MaybeUninit<T>], crate::aligned::PageDeleter> {
+        assert!(mem::align_of::<T>() <= crate::aligned::page_size());
+
+        let size = mem::size_of::<T>().checked_mul(len).expect("capacity overflow");
+        let ptr = crate::aligned::alloc_pages(size, true) as *mut mem::MaybeUninit<T>;
+        unsafe {
+            Unique::from_ptr_unchecked(ptr::slice_from_raw_parts_mut(ptr, len))
+        }
+    }
+
+    ///Fallible version of [new_zeroed_pages](#method.new_zeroed_pages).
+    ///
+    ///# Panics
+    ///
+    ///- If alignment of `T` is greater than page size.
+    pub fn try_new_zeroed_pages(len: usize) -> Result<Unique<'static, [mem::MaybeUninit<T>], crate::aligned::PageDeleter>, crate::Error> {
+        assert!(mem::align_of::<T>() <= crate::aligned::page_size());
+
+        let size = mem::size_of::<T>().checked_mul(len).ok_or(crate::Error::AllocFailed)?;
+        let ptr = crate::aligned::try_alloc_pages(size, true)? as *mut mem::MaybeUninit<T>;
+        unsafe {
+            Ok(Unique::from_ptr_unchecked(ptr::slice_from_raw_parts_mut(ptr, len)))
+        }
+    }
+
+    #[inline]
+    ///Creates new slice of `len` uninitialized elements, with memory filled by zero bytes.
+    ///
+    ///Memory is requested via `alloc_zeroed`, which is cheaper than zeroing it manually.
+    pub fn new_zeroed_slice(len: usize) -> Global<[mem::MaybeUninit<T>]> {
+        let ptr = crate::alloc_array::<mem::MaybeUninit<T>>(len, true);
+        unsafe {
+            Global::from_ptr_unchecked(ptr::slice_from_raw_parts_mut(ptr, len))
+        }
+    }
+
+    #[inline]
+    ///Fallible version of [new_zeroed_slice](#method.new_zeroed_slice).
+    pub fn try_new_zeroed_slice(len: usize) -> Result<Global<[mem::MaybeUninit<T>]>, crate::Error> {
+        let ptr = crate::try_alloc_array::<mem::MaybeUninit<T>>(len, true)?;
+        unsafe {
+            Ok(Global::from_ptr_unchecked(ptr::slice_from_raw_parts_mut(ptr, len)))
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> Global<[T; N]> {
+    #[inline]
+    ///Creates new array, initializing each element in place with `init`.
+    ///
+    ///Function receives index of the element.
+    ///
+    ///Unlike `boxed`, array is never placed on stack, therefore it is suitable for large arrays.
+    ///
+    ///```rust
+    ///use smart_ptr::unique::Global;
+    ///
+    ///let array = Global::<[usize; 1024 * 1024]>::boxed_array(|idx| idx);
+    ///assert_eq!(array[1024], 1024);
+    ///```
+    pub fn boxed_array<F: FnMut(usize) -> T>(init: F) -> Self {
+        let ptr = crate::init_array(N, init);
+        unsafe {
+            Self::from_ptr_unchecked(ptr as *mut [T; N])
+        }
+    }
+
+    #[inline]
+    ///Fallible version of [boxed_array](#method.boxed_array).
+    pub fn try_boxed_array<F: FnMut(usize) -> T>(init: F) -> Result<Self, crate::Error> {
+        let ptr = crate::try_init_array(N, init)?;
+        unsafe {
+            Ok(Self::from_ptr_unchecked(ptr as *mut [T; N]))
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> Unique<'a, T, crate::arena::ArenaDeleter> {
+    #[inline(always)]
+    ///Moves value into `arena`.
+    ///
+    ///```rust
+    ///use smart_ptr::Unique;
+    ///use smart_ptr::arena::Arena;
+    ///
+    ///let arena = Arena::new();
+    ///let value = Unique::boxed_in_arena(&arena, 1u32);
+    ///assert_eq!(*value, 1);
+    ///```
+    pub fn boxed_in_arena(arena: &'a crate::arena::Arena, val: T) -> Self {
+        arena.boxed(val)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Unique<'static, T, crate::RcDeleter> {
+    #[inline]
+    ///Takes ownership of `Rc`, if it is the only reference to the value.
+    ///
+    ///Otherwise returns `Rc` back, as value cannot be accessed mutably.
+    ///
+    ///```rust
+    ///use smart_ptr::{RcDeleter, Unique};
+    ///use std::rc::Rc;
+    ///
+    ///let shared = Rc::new(1u32);
+    ///let copy = shared.clone();
+    ///let shared = Unique::from_rc(shared).unwrap_err();
+    ///drop(copy);
+    ///
+    ///let mut value = Unique::from_rc(shared).expect("to be unique");
+    ///*value = 2;
+    ///assert_eq!(*value.into_rc(), 2);
+    ///```
+    pub fn from_rc(mut ptr: alloc::rc::Rc<T>) -> Result<Self, alloc::rc::Rc<T>> {
+        match alloc::rc::Rc::get_mut(&mut ptr) {
+            Some(_) => unsafe {
+                Ok(Self::from_ptr_unchecked(alloc::rc::Rc::into_raw(ptr) as *mut T))
+            },
+            None => Err(ptr),
+        }
+    }
+
+    #[inline]
+    ///Converts back into `Rc`.
+    pub fn into_rc(self) -> alloc::rc::Rc<T> {
+        let ptr = self.release();
+        unsafe {
+            alloc::rc::Rc::from_raw(ptr.as_ptr() as *const T)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Unique<'static, T, crate::ArcDeleter> {
+    #[inline]
+    ///Takes ownership of `Arc`, if it is the only reference to the value.
+    ///
+    ///Otherwise returns `Arc` back, as value cannot be accessed mutably.
+    ///Use [UniqueConst::from_arc](../unique_const/struct.UniqueConst.html#method.from_arc) to share value instead.
+    pub fn from_arc(mut ptr: alloc::sync::Arc<T>) -> Result<Self, alloc::sync::Arc<T>> {
+        match alloc::sync::Arc::get_mut(&mut ptr) {
+            Some(_) => unsafe {
+                Ok(Self::from_ptr_unchecked(alloc::sync::Arc::into_raw(ptr) as *mut T))
+            },
+            None => Err(ptr),
+        }
+    }
+
+    #[inline]
+    ///Converts back into `Arc`.
+    pub fn into_arc(self) -> alloc::sync::Arc<T> {
+        let ptr = self.release();
+        unsafe {
+            alloc::sync::Arc::from_raw(ptr.as_ptr() as *const T)
+        }
+    }
+}
+
+impl<T: crate::waker::Wake + 'static, D: crate::CloneWith<T> + 'static> Unique<'static, T, D> {
+    #[inline(always)]
+    ///Converts into `RawWaker`, which owns the object.
+    ///
+    ///Cloning waker clones the object via deleter, while dropping it disposes of the object.
+    pub fn into_raw_waker(self) -> core::task::RawWaker {
+        crate::waker::raw_waker(self)
+    }
+
+    #[inline(always)]
+    ///Converts into `Waker`, which owns the object.
+    ///
+    ///Cloning waker clones the object via deleter, while dropping it disposes of the object.
+    pub fn into_waker(self) -> core::task::Waker {
+        unsafe {
+            core::task::Waker::from_raw(self.into_raw_waker())
+        }
+    }
+}
+
+impl<'a, D: Deleter> Unique<'a, [u8], D> {
+    #[inline(always)]
+    ///Gets bytes of the buffer.
+    pub fn as_bytes(&self) -> &[u8] {
+        self
+    }
+
+    #[inline(always)]
+    ///Gets mutable bytes of the buffer.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self
+    }
+
+    #[inline(always)]
+    ///Copies all bytes from `src` into the buffer.
+    ///
+    ///# Panics
+    ///
+    ///- If `src` length is not equal to the buffer's length.
+    pub fn copy_from_slice(&mut self, src: &[u8]) {
+        self.as_bytes_mut().copy_from_slice(src)
+    }
+
+    #[inline(always)]
+    ///Fills buffer with `byte`.
+    pub fn fill(&mut self, byte: u8) {
+        self.as_bytes_mut().fill(byte)
+    }
+}
+
+//Validates that `T` is zero sized at compile time.
+struct AssertZeroSized<T>(marker::PhantomData<T>);
+
+impl<T> AssertZeroSized<T> {
+    const OK: () = assert!(mem::size_of::<T>() == 0, "Type must be zero sized");
+}
+
+#[cfg(feature = "bytemuck")]
+//Validates that `U` has the same layout as `T` at compile time.
+struct AssertSameLayout<T, U>(marker::PhantomData<(T, U)>);
+
+#[cfg(feature = "bytemuck")]
+impl<T, U> AssertSameLayout<T, U> {
+    const OK: () = assert!(mem::size_of::<T>() == mem::size_of::<U>() && mem::align_of::<T>() == mem::align_of::<U>(), "Types must have the same size and alignment");
+}
+
+#[cfg(feature = "bytemuck")]
+impl<'a, T: bytemuck::Pod, D: Deleter> Unique<'a, T, D> {
+    #[inline(always)]
+    ///Gets bytes of the value.
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self.as_ref())
+    }
+
+    #[inline(always)]
+    ///Gets mutable bytes of the value.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::bytes_of_mut(self.as_mut())
+    }
+
+    #[inline]
+    ///Reinterprets value as another plain old data type.
+    ///
+    ///Both types must have the same size and alignment, so that deleter receives pointer with the
+    ///same layout. It is verified at compile time.
+    ///
+    ///```rust
+    ///use smart_ptr::Unique;
+    ///
+    ///let mut value = 1.0f32.to_bits();
+    ///let ptr = Unique::<u32, ()>::from(&mut value);
+    ///let ptr = ptr.cast_pod::<f32>();
+    ///assert_eq!(*ptr, 1.0);
+    ///```
+    pub fn cast_pod<U: bytemuck::Pod>(self) -> Unique<'a, U, D> {
+        #[allow(clippy::let_unit_value)]
+        let _ = AssertSameLayout::<T, U>::OK;
+
+        unsafe {
+            Unique::from_ptr_unchecked(self.release().as_ptr() as *mut U)
+        }
+    }
 }
 
 #[repr(transparent)]
@@ -69,6 +744,31 @@ impl<'a, T: ?Sized, D: Deleter> Unique<'a, T, D> {
         Self::from_ptr_unchecked(ptr)
     }
 
+    #[inline]
+    ///Creates new instance from raw pointer, returning error if pointer is null.
+    ///
+    ///Unlike `new` it never panics, therefore it is suitable for contexts where unwinding is
+    ///forbidden, like FFI callbacks.
+    ///
+    ///```rust
+    ///use smart_ptr::{Unique, NullPtrError};
+    ///
+    ///fn wrap(ptr: *mut u32) -> Result<u32, NullPtrError> {
+    ///    let ptr = unsafe { Unique::<u32, ()>::try_new(ptr)? };
+    ///    Ok(*ptr)
+    ///}
+    ///
+    ///let mut value = 1;
+    ///assert_eq!(wrap(&mut value), Ok(1));
+    ///assert_eq!(wrap(core::ptr::null_mut()), Err(NullPtrError));
+    ///```
+    pub unsafe fn try_new(ptr: *mut T) -> Result<Self, crate::NullPtrError> {
+        match ptr.is_null() {
+            true => Err(crate::NullPtrError),
+            false => Ok(Self::from_ptr_unchecked(ptr)),
+        }
+    }
+
     #[inline]
     ///Creates instance from raw pointer, checking if pointer is null.
     ///
@@ -84,7 +784,21 @@ impl<'a, T: ?Sized, D: Deleter> Unique<'a, T, D> {
     ///Creates instance from raw pointer, without checking if pointer is null.
     ///
     ///User must ensure that pointer is non-null
+    ///
+    ///With `nightly` feature, alignment of pointer is verified in debug builds.
     pub unsafe fn from_ptr_unchecked(ptr: *mut T) -> Self {
+        //Alignment is read from pointer's metadata, as creating reference to misaligned pointer is UB
+        #[cfg(feature = "nightly")]
+        debug_assert!(ptr.is_null() || (ptr as *mut u8 as usize) & (mem::align_of_val_raw(ptr) - 1) == 0, "Pointer is misaligned");
+        #[cfg(feature = "leak-tracking")]
+        crate::leak::register(ptr);
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::increment::<T>();
+        #[cfg(feature = "debug-guards")]
+        if !D::SHARED {
+            crate::guards::revive::<Self>(ptr as *mut u8 as usize);
+        }
+
         Self {
             inner: ptr::NonNull::new_unchecked(ptr),
             _traits: marker::PhantomData,
@@ -92,80 +806,501 @@ impl<'a, T: ?Sized, D: Deleter> Unique<'a, T, D> {
     }
 
     #[inline(always)]
-    ///Gets underlying raw pointer.
-    ///
-    ///Note that it is illegal to create multiple mutable references
-    ///so care must be taken when converting raw pointer into mutable reference.
-    pub fn get(&self) -> *mut T {
-        self.inner.as_ptr()
+    ///Gets underlying raw pointer.
+    ///
+    ///Note that it is illegal to create multiple mutable references
+    ///so care must be taken when converting raw pointer into mutable reference.
+    pub fn get(&self) -> *mut T {
+        self.assert_alive();
+        self.inner.as_ptr()
+    }
+
+    #[inline(always)]
+    ///Gets underlying non-null pointer.
+    pub fn as_non_null(&self) -> ptr::NonNull<T> {
+        self.assert_alive();
+        self.inner
+    }
+
+    #[inline(always)]
+    ///Gets reference to underlying data.
+    pub fn as_ref(&self) -> &T {
+        self
+    }
+
+    #[inline(always)]
+    ///Gets mutable reference to underlying data.
+    pub fn as_mut(&mut self) -> &mut T {
+        self
+    }
+
+    #[inline(always)]
+    ///Retrieves pointer as of type
+    pub fn cast<N>(&self) -> *mut N {
+        self.get() as *mut N
+    }
+
+    #[inline(always)]
+    ///Retrieves pointer as of type and const
+    pub fn const_cast<N>(&self) -> *const N {
+        self.get() as *mut N as *const N
+    }
+
+    #[inline]
+    ///Retrieves pointer as of type, if it is suitably aligned for `N` and value is big enough to hold `N`.
+    ///
+    ///```rust
+    ///use smart_ptr::Unique;
+    ///
+    ///let mut value = 0u64;
+    ///let ptr = Unique::<u64, ()>::from(&mut value);
+    ///assert_eq!(ptr.checked_cast::<[u32; 2]>(), Some(ptr.get() as *mut [u32; 2]));
+    ///assert_eq!(ptr.checked_cast::<[u32; 3]>(), None);
+    ///```
+    pub fn checked_cast<N>(&self) -> Option<*mut N> {
+        let ptr = self.get();
+        let is_aligned = (ptr as *mut u8 as usize) & (mem::align_of::<N>() - 1) == 0;
+        match is_aligned && mem::size_of_val(self.as_ref()) >= mem::size_of::<N>() {
+            true => Some(ptr as *mut N),
+            false => None,
+        }
+    }
+
+    #[inline]
+    ///Converts into pointer of type `N`, if it has the same size and alignment as the value.
+    ///
+    ///Layout must be the same, so that deleter receives pointer with the same layout.
+    ///
+    ///Returns original pointer otherwise.
+    pub fn try_cast<N>(self) -> Result<Unique<'a, N, D>, Self> {
+        let value = self.as_ref();
+        match mem::size_of_val(value) == mem::size_of::<N>() && mem::align_of_val(value) == mem::align_of::<N>() {
+            true => unsafe {
+                Ok(Unique::from_ptr_unchecked(self.release().as_ptr() as *mut N))
+            },
+            false => Err(self),
+        }
+    }
+
+    #[inline(always)]
+    ///Swaps underlying pointers between instances
+    pub fn swap(&mut self, other: &mut Self) {
+        mem::swap(&mut self.inner, &mut other.inner);
+    }
+
+    #[doc(hidden)]
+    #[inline(always)]
+    ///Implementation of `unsize!`, which relies on `cast` to only perform unsizing coercion.
+    pub unsafe fn __unsize<U: ?Sized, F: FnOnce(*mut T) -> *mut U>(self, cast: F) -> Unique<'a, U, D> {
+        Unique::from_ptr_unchecked(cast(self.release().as_ptr()))
+    }
+
+    #[inline(always)]
+    ///Converts into read-only pointer, preserving ownership.
+    ///
+    ///```rust
+    ///use smart_ptr::Unique;
+    ///
+    ///let mut value = 0u32;
+    ///let mut ptr = Unique::<u32, ()>::from(&mut value);
+    ///*ptr = 1;
+    ///let ptr = ptr.into_const();
+    ///assert_eq!(*ptr, 1);
+    ///```
+    pub fn into_const(self) -> crate::UniqueConst<'a, T, D> {
+        unsafe {
+            crate::UniqueConst::from_ptr_unchecked(self.release().as_ptr())
+        }
+    }
+
+    #[inline(always)]
+    ///Converts into pinned pointer.
+    ///
+    ///User must guarantee that value will not be moved out of its memory until it is disposed of
+    ///by deleter (e.g. it is not safe for borrowed pointers).
+    pub unsafe fn into_pin_unchecked(self) -> core::pin::Pin<Self> {
+        core::pin::Pin::new_unchecked(self)
+    }
+
+    #[inline]
+    #[allow(unused_mut)]
+    ///Releases the ownership and returns raw pointer, without dropping it.
+    pub fn release(mut self) -> ptr::NonNull<T> {
+        self.assert_alive();
+        let result = self.inner;
+        #[cfg(feature = "leak-tracking")]
+        crate::leak::unregister(result.as_ptr());
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::decrement::<T>();
+        #[cfg(feature = "debug-guards")]
+        self.poison(mem::size_of_val(unsafe { &*result.as_ptr() }));
+        mem::forget(self);
+        result
+    }
+
+    #[inline(always)]
+    fn assert_alive(&self) {
+        #[cfg(feature = "debug-guards")]
+        assert!(self.is_alive(), "Unique is used after it was released or deleted");
+    }
+
+    #[cfg(feature = "debug-guards")]
+    #[inline(always)]
+    fn is_alive(&self) -> bool {
+        let address = self.inner.as_ptr() as *mut u8 as usize;
+        address != POISON && (D::SHARED || !crate::guards::is_disposed::<Self>(address))
+    }
+
+    #[cfg(feature = "debug-guards")]
+    #[inline(always)]
+    fn poison(&mut self, size: usize) {
+        let ptr = self.inner.as_ptr();
+        //Zero sized values share address, therefore cannot be tracked.
+        if size != 0 && !D::SHARED {
+            crate::guards::dispose::<Self>(ptr as *mut u8 as usize);
+        }
+
+        let ptr = ptr.wrapping_byte_add(POISON.wrapping_sub(ptr as *mut u8 as usize));
+        //Volatile write, as compiler is free to discard store to object that is no longer used.
+        unsafe {
+            ptr::write_volatile(&mut self.inner, ptr::NonNull::new_unchecked(ptr));
+        }
+    }
+}
+
+impl<'a, T, D: Deleter> Unique<'a, T, D> {
+    #[inline]
+    ///Creates new instance from raw pointer, verifying that it is non-null and properly aligned.
+    ///
+    ///```rust
+    ///use smart_ptr::{Unique, Error};
+    ///
+    ///let mut value = 0u32;
+    ///let ptr = &mut value as *mut u32;
+    ///assert!(unsafe { Unique::<u32, ()>::new_aligned(ptr) }.is_ok());
+    ///
+    ///let misaligned = (ptr as usize + 1) as *mut u32;
+    ///let error = unsafe { Unique::<u32, ()>::new_aligned(misaligned) }.unwrap_err();
+    ///assert_eq!(error, Error::Misaligned { required: 4, found: misaligned as usize });
+    ///```
+    pub unsafe fn new_aligned(ptr: *mut T) -> Result<Self, crate::Error> {
+        if ptr.is_null() {
+            return Err(crate::Error::Null);
+        }
+
+        let required = mem::align_of::<T>();
+        let found = ptr as usize;
+        match found & (required - 1) {
+            0 => Ok(Self::from_ptr_unchecked(ptr)),
+            _ => Err(crate::Error::Misaligned {
+                required,
+                found,
+            }),
+        }
+    }
+
+    #[inline]
+    ///Swaps values of both pointers, leaving pointers themselves intact.
+    ///
+    ///Unlike `swap`, addresses remain stable, which is required when value is registered elsewhere.
+    ///
+    ///```rust
+    ///use smart_ptr::Unique;
+    ///
+    ///let mut first = 1u32;
+    ///let mut second = 2u32;
+    ///let mut first_ptr = Unique::<u32, ()>::from(&mut first);
+    ///let mut second_ptr = Unique::<u32, ()>::from(&mut second);
+    ///let address = first_ptr.get();
+    ///first_ptr.swap_values(&mut second_ptr);
+    ///assert_eq!(first_ptr.get(), address);
+    ///assert_eq!(*first_ptr, 2);
+    ///assert_eq!(*second_ptr, 1);
+    ///```
+    pub fn swap_values<D2: Deleter>(&mut self, other: &mut Unique<'_, T, D2>) {
+        mem::swap(self.as_mut(), other.as_mut())
+    }
+}
+
+impl<'a, T> Unique<'a, T, crate::DropInPlace> {
+    #[inline]
+    ///Constructs value in caller provided `storage`, which is borrowed for the lifetime of pointer.
+    ///
+    ///On `Drop` only destructor is invoked, therefore storage can reside on stack or in static memory.
+    ///
+    ///```rust
+    ///use smart_ptr::Unique;
+    ///use core::mem::MaybeUninit;
+    ///
+    ///let mut storage = MaybeUninit::uninit();
+    ///let value = Unique::emplace(&mut storage, String::from("stack"));
+    ///assert_eq!(*value, "stack");
+    ///```
+    pub fn emplace(storage: &'a mut mem::MaybeUninit<T>, val: T) -> Self {
+        let ptr = storage.write(val) as *mut T;
+        unsafe {
+            Self::from_ptr_unchecked(ptr)
+        }
+    }
+
+    ///Constructs value in caller provided byte `storage`, placing it at first properly aligned offset.
+    ///
+    ///Returns value back, if it doesn't fit into storage.
+    ///
+    ///```rust
+    ///use smart_ptr::Unique;
+    ///use core::mem::MaybeUninit;
+    ///
+    ///let mut storage = [MaybeUninit::<u8>::uninit(); 16];
+    ///let value = Unique::emplace_in(&mut storage, 1u64).expect("to fit");
+    ///assert_eq!(*value, 1);
+    ///```
+    pub fn emplace_in(storage: &'a mut [mem::MaybeUninit<u8>], val: T) -> Result<Self, T> {
+        let offset = storage.as_mut_ptr().align_offset(mem::align_of::<T>());
+        match offset.checked_add(mem::size_of::<T>()) {
+            Some(end) if end <= storage.len() => unsafe {
+                let ptr = storage.as_mut_ptr().add(offset) as *mut T;
+                ptr.write(val);
+                Ok(Self::from_ptr_unchecked(ptr))
+            },
+            _ => Err(val),
+        }
+    }
+}
+
+impl<'a, T, D: Deleter> Unique<'a, mem::MaybeUninit<T>, D> {
+    #[inline]
+    ///Initializes value, returning pointer to initialized value.
+    pub fn write(mut self, val: T) -> Unique<'a, T, D> {
+        self.as_mut().write(val);
+        unsafe {
+            self.assume_init()
+        }
+    }
+
+    #[inline]
+    ///Initializes value by filling its memory with zero bytes, returning pointer to initialized value.
+    ///
+    ///User must guarantee that all zero bytes is valid value of `T`.
+    pub unsafe fn write_zeroed(self) -> Unique<'a, T, D> {
+        ptr::write_bytes(self.get(), 0, 1);
+        self.assume_init()
+    }
+
+    #[inline]
+    ///Converts to pointer of initialized value.
+    ///
+    ///User must guarantee that value is initialized.
+    pub unsafe fn assume_init(self) -> Unique<'a, T, D> {
+        Unique::from_ptr_unchecked(self.release().as_ptr() as *mut T)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T, D: Deleter> Unique<'a, [T], D> {
+    ///Splits slice into two owned parts at `mid` index, without copying.
+    ///
+    ///Each part drops its own elements, while original block is disposed of with deleter `D` once
+    ///both parts are dropped.
+    ///
+    ///# Panics
+    ///
+    ///- If `mid > len`.
+    ///
+    ///```rust
+    ///use smart_ptr::unique::Global;
+    ///
+    ///let buffer = Global::from_vec(vec![1, 2, 3, 4, 5]);
+    ///let (header, body) = buffer.split_at(2);
+    ///assert_eq!(*header, [1, 2]);
+    ///let (body, tail) = body.split_at(2);
+    ///assert_eq!(*body, [3, 4]);
+    ///assert_eq!(*tail, [5]);
+    ///```
+    pub fn split_at(self, mid: usize) -> (crate::split::SplitPart<'a, T, D>, crate::split::SplitPart<'a, T, D>) {
+        assert!(mid <= self.len(), "mid > len");
+
+        let ptr = self.release().as_ptr();
+        unsafe {
+            crate::split::split(ptr, mid, crate::split::SplitDeleter::new(ptr))
+        }
+    }
+}
+
+impl<'a, T, D: Deleter> Unique<'a, [mem::MaybeUninit<T>], D> {
+    #[inline]
+    ///Converts to pointer of initialized slice.
+    ///
+    ///User must guarantee that all elements are initialized.
+    pub unsafe fn assume_init(self) -> Unique<'a, [T], D> {
+        let len = self.len();
+        let ptr = self.release().as_ptr() as *mut T;
+        Unique::from_ptr_unchecked(ptr::slice_from_raw_parts_mut(ptr, len))
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> Drop for Unique<'a, T, D> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        //Panic within `Drop` is likely to abort, hence pointer, that is already deleted, is merely skipped.
+        #[cfg(feature = "debug-guards")]
+        if !self.is_alive() {
+            return;
+        }
+
+        let ptr = self.inner.as_ptr();
+        let layout = unsafe {
+            core::alloc::Layout::for_value(&*ptr)
+        };
+        unsafe {
+            D::delete_with_layout::<T>(ptr, layout)
+        }
+        #[cfg(feature = "leak-tracking")]
+        crate::leak::unregister(self.inner.as_ptr());
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::decrement::<T>();
+        #[cfg(feature = "debug-guards")]
+        self.poison(layout.size());
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> crate::OwnedPtr for Unique<'a, T, D> {
+    type Target = T;
+
+    #[inline(always)]
+    fn get(&self) -> *mut Self::Target {
+        Unique::get(self)
+    }
+
+    #[inline(always)]
+    fn release(self) -> ptr::NonNull<Self::Target> {
+        Unique::release(self)
+    }
+
+    #[inline(always)]
+    unsafe fn from_non_null(ptr: ptr::NonNull<Self::Target>) -> Self {
+        Self::from_ptr_unchecked(ptr.as_ptr())
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> fmt::Pointer for Unique<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.inner, fmt)
+    }
+}
+
+impl<'a, T: ?Sized + fmt::Debug, D: Deleter> fmt::Debug for Unique<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_ref(), fmt)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<'a, T: ?Sized + defmt::Format, D: Deleter> defmt::Format for Unique<'a, T, D> {
+    #[inline(always)]
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::Format::format(self.as_ref(), fmt)
+    }
+}
+
+impl<'a, T: ?Sized + fmt::Display, D: Deleter> fmt::Display for Unique<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_ref(), fmt)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized + std::error::Error, D: Deleter> std::error::Error for Unique<'a, T, D> {
+    #[inline(always)]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.as_ref().source()
+    }
+
+    #[allow(deprecated)]
+    #[inline(always)]
+    fn description(&self) -> &str {
+        self.as_ref().description()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized + std::io::Read, D: Deleter> std::io::Read for Unique<'a, T, D> {
+    #[inline(always)]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.as_mut().read(buf)
     }
 
     #[inline(always)]
-    ///Gets reference to underlying data.
-    pub fn as_ref(&self) -> &T {
-        self
+    fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize> {
+        self.as_mut().read_vectored(bufs)
     }
 
     #[inline(always)]
-    ///Gets mutable reference to underlying data.
-    pub fn as_mut(&mut self) -> &mut T {
-        self
+    fn read_to_end(&mut self, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> {
+        self.as_mut().read_to_end(buf)
     }
 
     #[inline(always)]
-    ///Retrieves pointer as of type
-    pub fn cast<N>(&self) -> *mut N {
-        self.inner.as_ptr() as *mut N
+    fn read_to_string(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> {
+        self.as_mut().read_to_string(buf)
     }
 
     #[inline(always)]
-    ///Retrieves pointer as of type and const
-    pub fn const_cast<N>(&self) -> *const N {
-        self.inner.as_ptr() as *mut N as *const N
+    fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+        self.as_mut().read_exact(buf)
     }
+}
 
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized + std::io::Write, D: Deleter> std::io::Write for Unique<'a, T, D> {
     #[inline(always)]
-    ///Swaps underlying pointers between instances
-    pub fn swap(&mut self, other: &mut Self) {
-        mem::swap(&mut self.inner, &mut other.inner);
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.as_mut().write(buf)
     }
 
-    #[inline]
-    ///Releases the ownership and returns raw pointer, without dropping it.
-    pub fn release(self) -> ptr::NonNull<T> {
-        let result = self.inner;
-        mem::forget(self);
-        result
+    #[inline(always)]
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+        self.as_mut().write_vectored(bufs)
     }
-}
 
-impl<'a, T: ?Sized, D: Deleter> Drop for Unique<'a, T, D> {
     #[inline(always)]
-    fn drop(&mut self) {
-        unsafe {
-            D::delete::<T>(self.inner.as_ptr())
-        }
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.as_mut().flush()
     }
-}
 
-impl<'a, T: ?Sized, D: Deleter> fmt::Pointer for Unique<'a, T, D> {
     #[inline(always)]
-    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Pointer::fmt(&self.inner, fmt)
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.as_mut().write_all(buf)
+    }
+
+    #[inline(always)]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> std::io::Result<()> {
+        self.as_mut().write_fmt(args)
     }
 }
 
-impl<'a, T: ?Sized + fmt::Debug, D: Deleter> fmt::Debug for Unique<'a, T, D> {
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized + std::io::Seek, D: Deleter> std::io::Seek for Unique<'a, T, D> {
     #[inline(always)]
-    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(&self.as_ref(), fmt)
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.as_mut().seek(pos)
+    }
+
+    #[inline(always)]
+    fn stream_position(&mut self) -> std::io::Result<u64> {
+        self.as_mut().stream_position()
     }
 }
 
-impl<'a, T: ?Sized + fmt::Display, D: Deleter> fmt::Display for Unique<'a, T, D> {
+impl<'a, F: ?Sized + core::future::Future + Unpin, D: Deleter> core::future::Future for Unique<'a, F, D> {
+    type Output = F::Output;
+
     #[inline(always)]
-    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.as_ref(), fmt)
+    fn poll(mut self: core::pin::Pin<&mut Self>, ctx: &mut core::task::Context<'_>) -> core::task::Poll<Self::Output> {
+        F::poll(core::pin::Pin::new(self.as_mut().get_mut().as_mut()), ctx)
     }
 }
 
@@ -181,7 +1316,7 @@ impl<'a, T: ?Sized, D: Deleter> core::ops::Deref for Unique<'a, T, D> {
     #[inline]
     fn deref(&self) -> &Self::Target {
         unsafe {
-            &*self.inner.as_ptr()
+            &*self.get()
         }
     }
 }
@@ -190,7 +1325,7 @@ impl<'a, T: ?Sized, D: Deleter> core::ops::DerefMut for Unique<'a, T, D> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         unsafe {
-            &mut *self.inner.as_ptr()
+            &mut *self.get()
         }
     }
 }
@@ -225,12 +1360,163 @@ impl<'a, T: ?Sized + PartialEq, D: Deleter> PartialEq<Self> for Unique<'a, T, D>
 impl<'a, T: ?Sized + Eq, D: Deleter> Eq for Unique<'a, T, D> {
 }
 
+impl<'a, T: ?Sized + PartialEq, D: Deleter> PartialEq<T> for Unique<'a, T, D> {
+    #[inline(always)]
+    fn eq(&self, other: &T) -> bool {
+        PartialEq::eq(self.as_ref(), other)
+    }
+}
+
+impl<'a, T: ?Sized + PartialOrd, D: Deleter> PartialOrd<T> for Unique<'a, T, D> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &T) -> Option<core::cmp::Ordering> {
+        PartialOrd::partial_cmp(self.as_ref(), other)
+    }
+}
+
+impl<'a, 'b, D: Deleter> PartialEq<&'b str> for Unique<'a, str, D> {
+    #[inline(always)]
+    fn eq(&self, other: &&'b str) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl<'a, 'b, D: Deleter> PartialOrd<&'b str> for Unique<'a, str, D> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &&'b str) -> Option<core::cmp::Ordering> {
+        PartialOrd::partial_cmp(self.as_ref(), *other)
+    }
+}
+
+impl<'a, D: Deleter> PartialEq<Unique<'a, str, D>> for str {
+    #[inline(always)]
+    fn eq(&self, other: &Unique<'a, str, D>) -> bool {
+        self == other.as_ref()
+    }
+}
+
+impl<'a, D: Deleter> PartialEq<Unique<'a, str, D>> for &str {
+    #[inline(always)]
+    fn eq(&self, other: &Unique<'a, str, D>) -> bool {
+        *self == other.as_ref()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, D: Deleter> PartialEq<alloc::string::String> for Unique<'a, str, D> {
+    #[inline(always)]
+    fn eq(&self, other: &alloc::string::String) -> bool {
+        self.as_ref() == other.as_str()
+    }
+}
+
+impl<'a, 'b, T: PartialEq<U>, U, D: Deleter> PartialEq<&'b [U]> for Unique<'a, [T], D> {
+    #[inline(always)]
+    fn eq(&self, other: &&'b [U]) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl<'a, T: PartialEq<U>, U, D: Deleter, const N: usize> PartialEq<[U; N]> for Unique<'a, [T], D> {
+    #[inline(always)]
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.as_ref() == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: PartialEq<U>, U, D: Deleter> PartialEq<alloc::vec::Vec<U>> for Unique<'a, [T], D> {
+    #[inline(always)]
+    fn eq(&self, other: &alloc::vec::Vec<U>) -> bool {
+        self.as_ref() == other.as_slice()
+    }
+}
+
 impl<'a, T: ?Sized + core::panic::RefUnwindSafe, D: Deleter> core::panic::UnwindSafe for Unique<'a, T, D> {
 }
 
 impl<'a, T: ?Sized + core::panic::RefUnwindSafe, D: Deleter> core::panic::RefUnwindSafe for Unique<'a, T, D> {
 }
 
+#[cfg(feature = "nightly")]
+impl<'a, Args: core::marker::Tuple, F: ?Sized + FnMut<Args>, D: Deleter> FnOnce<Args> for Unique<'a, F, D> {
+    type Output = F::Output;
+
+    #[inline(always)]
+    extern "rust-call" fn call_once(mut self, args: Args) -> Self::Output {
+        //Value cannot be moved out, as its memory belongs to deleter
+        self.as_mut().call_mut(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, Args: core::marker::Tuple, F: ?Sized + FnMut<Args>, D: Deleter> FnMut<Args> for Unique<'a, F, D> {
+    #[inline(always)]
+    extern "rust-call" fn call_mut(&mut self, args: Args) -> Self::Output {
+        self.as_mut().call_mut(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, Args: core::marker::Tuple, F: ?Sized + Fn<Args>, D: Deleter> Fn<Args> for Unique<'a, F, D> {
+    #[inline(always)]
+    extern "rust-call" fn call(&self, args: Args) -> Self::Output {
+        self.as_ref().call(args)
+    }
+}
+
+//Operators are delegated to the reference of the value, the same way as for `&Wrapping<T>`.
+macro_rules! impl_binary_op {
+    ($($op:ident::$method:ident, $op_assign:ident::$method_assign:ident;)+) => {$(
+        impl<'b, 'a, T: ?Sized, D: Deleter, Rhs> core::ops::$op<Rhs> for &'b Unique<'a, T, D> where &'b T: core::ops::$op<Rhs> {
+            type Output = <&'b T as core::ops::$op<Rhs>>::Output;
+
+            #[inline(always)]
+            fn $method(self, rhs: Rhs) -> Self::Output {
+                core::ops::$op::$method(self.as_ref(), rhs)
+            }
+        }
+
+        impl<'a, T: ?Sized + core::ops::$op_assign<Rhs>, D: Deleter, Rhs> core::ops::$op_assign<Rhs> for Unique<'a, T, D> {
+            #[inline(always)]
+            fn $method_assign(&mut self, rhs: Rhs) {
+                core::ops::$op_assign::$method_assign(self.as_mut(), rhs)
+            }
+        }
+    )+};
+}
+
+impl_binary_op!(
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+    Div::div, DivAssign::div_assign;
+    Rem::rem, RemAssign::rem_assign;
+    BitAnd::bitand, BitAndAssign::bitand_assign;
+    BitOr::bitor, BitOrAssign::bitor_assign;
+    BitXor::bitxor, BitXorAssign::bitxor_assign;
+    Shl::shl, ShlAssign::shl_assign;
+    Shr::shr, ShrAssign::shr_assign;
+);
+
+macro_rules! impl_unary_op {
+    ($($op:ident::$method:ident;)+) => {$(
+        impl<'b, 'a, T: ?Sized, D: Deleter> core::ops::$op for &'b Unique<'a, T, D> where &'b T: core::ops::$op {
+            type Output = <&'b T as core::ops::$op>::Output;
+
+            #[inline(always)]
+            fn $method(self) -> Self::Output {
+                core::ops::$op::$method(self.as_ref())
+            }
+        }
+    )+};
+}
+
+impl_unary_op!(
+    Neg::neg;
+    Not::not;
+);
+
 #[cfg(feature = "alloc")]
 impl<T: ?Sized> From<alloc::boxed::Box<T>> for Global<T> {
     #[inline]
@@ -242,17 +1528,210 @@ impl<T: ?Sized> From<alloc::boxed::Box<T>> for Global<T> {
     }
 }
 
-#[cfg(feature = "alloc")]
-impl<T: ?Sized + Clone> Clone for Global<T> {
+#[cfg(feature = "std")]
+impl From<std::ffi::OsString> for Global<std::ffi::OsStr> {
+    #[inline]
+    fn from(string: std::ffi::OsString) -> Self {
+        string.into_boxed_os_str().into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::path::PathBuf> for Global<std::path::Path> {
+    #[inline]
+    fn from(path: std::path::PathBuf) -> Self {
+        path.into_boxed_path().into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, D: Deleter> Unique<'a, std::ffi::OsStr, D> {
+    #[inline(always)]
+    ///Gets reference to platform string.
+    pub fn as_os_str(&self) -> &std::ffi::OsStr {
+        self
+    }
+
+    #[inline(always)]
+    ///Copies platform string into owned `OsString`.
+    pub fn to_os_string(&self) -> std::ffi::OsString {
+        self.as_os_str().to_os_string()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Global<std::ffi::OsStr> {
+    #[inline]
+    ///Converts into `OsString` without copying.
+    pub fn into_os_string(self) -> std::ffi::OsString {
+        self.into_boxed().into_os_string()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, D: Deleter> Unique<'a, std::path::Path, D> {
+    #[inline(always)]
+    ///Gets reference to path.
+    pub fn as_path(&self) -> &std::path::Path {
+        self
+    }
+
+    #[inline(always)]
+    ///Copies path into owned `PathBuf`.
+    pub fn to_path_buf(&self) -> std::path::PathBuf {
+        self.as_path().to_path_buf()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Global<std::path::Path> {
+    #[inline]
+    ///Converts into `PathBuf` without copying.
+    ///
+    ///```rust
+    ///use smart_ptr::unique::Global;
+    ///use std::path::{Path, PathBuf};
+    ///
+    ///let path = Global::<Path>::from(PathBuf::from("/tmp"));
+    ///assert_eq!(path.as_path(), Path::new("/tmp"));
+    ///assert_eq!(path.clone().into_path_buf(), PathBuf::from("/tmp"));
+    ///```
+    pub fn into_path_buf(self) -> std::path::PathBuf {
+        self.into_boxed().into_path_buf()
+    }
+}
+
+impl<'a, T: ?Sized, D: crate::CloneWith<T>> Clone for Unique<'a, T, D> {
+    #[inline]
     fn clone(&self) -> Self {
-        let val = unsafe {
-            //Make sure not to drop unnecessary
-            core::mem::ManuallyDrop::new(
-                alloc::boxed::Box::from_raw(self.get())
-            )
-        };
-        let result = core::mem::ManuallyDrop::into_inner(val.clone());
-        result.into()
+        unsafe {
+            Self::new(D::clone_ptr(self.get() as *const T))
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> From<Global<T>> for alloc::rc::Rc<T> {
+    #[inline(always)]
+    fn from(ptr: Global<T>) -> Self {
+        ptr.into_rc()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> From<Global<T>> for alloc::sync::Arc<T> {
+    #[inline(always)]
+    fn from(ptr: Global<T>) -> Self {
+        ptr.into_arc()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> From<Global<T>> for core::pin::Pin<Global<T>> {
+    #[inline(always)]
+    fn from(ptr: Global<T>) -> Self {
+        //Global pointer owns its memory, hence value cannot be moved out of it
+        unsafe {
+            ptr.into_pin_unchecked()
+        }
+    }
+}
+
+impl<'a, T> Unique<'a, T, crate::NoopDeleter> {
+    #[inline]
+    ///Creates well-aligned dangling pointer to zero sized type, which never interacts with deleter.
+    ///
+    ///Useful as sentinel or empty state, that requires neither allocation nor fake pointer.
+    ///
+    ///Size of `T` is checked at compile time.
+    ///
+    ///```rust
+    ///use smart_ptr::Unique;
+    ///
+    ///let empty = Unique::<(), _>::dangling();
+    ///assert_eq!(empty.get() as usize, core::mem::align_of::<()>());
+    ///```
+    ///
+    ///```compile_fail
+    ///use smart_ptr::Unique;
+    ///
+    ///let empty = Unique::<u32, _>::dangling();
+    ///```
+    pub fn dangling() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = AssertZeroSized::<T>::OK;
+
+        unsafe {
+            Self::from_ptr_unchecked(ptr::NonNull::dangling().as_ptr())
+        }
+    }
+}
+
+impl<T: ?Sized> Unique<'static, T, crate::NoopDeleter> {
+    #[inline(always)]
+    ///Creates new instance from static or leaked data, which is never disposed of.
+    ///
+    ///```rust
+    ///use smart_ptr::Unique;
+    ///
+    ///let value = Box::leak(Box::new(1u32));
+    ///let ptr = Unique::from_static(value);
+    ///assert_eq!(*ptr, 1);
+    ///```
+    pub fn from_static(val: &'static mut T) -> Self {
+        val.into()
+    }
+}
+
+impl<'a, T: ?Sized> Unique<'a, T, ()> {
+    #[inline]
+    ///Creates new instance from raw pointer and closure, which is invoked to dispose of it.
+    ///
+    ///# Panics
+    ///
+    ///- If pointer is null
+    ///
+    ///```rust
+    ///use smart_ptr::Unique;
+    ///
+    ///let mut is_deleted = false;
+    ///let mut value = 1u32;
+    ///let ptr = unsafe { Unique::with_deleter(&mut value as *mut u32, |ptr| {
+    ///    *ptr = 0;
+    ///    is_deleted = true;
+    ///}) };
+    ///drop(ptr);
+    ///assert!(is_deleted);
+    ///assert_eq!(value, 0);
+    ///```
+    pub unsafe fn with_deleter<F: FnOnce(*mut T)>(ptr: *mut T, deleter: F) -> crate::StatefulUnique<'a, T, F> {
+        crate::StatefulUnique::new(ptr, deleter)
+    }
+
+    #[inline]
+    ///Converts back into mutable reference, which is inverse of `From<&'a mut T>`.
+    ///
+    ///```rust
+    ///use smart_ptr::Unique;
+    ///
+    ///let mut value = 1u32;
+    ///let ptr = Unique::<u32, ()>::from(&mut value);
+    ///let value = ptr.into_mut();
+    ///*value += 1;
+    ///assert_eq!(*value, 2);
+    ///```
+    pub fn into_mut(self) -> &'a mut T {
+        unsafe {
+            &mut *self.release().as_ptr()
+        }
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> From<Unique<'a, T, D>> for ptr::NonNull<T> {
+    #[inline(always)]
+    ///Releases the ownership, equivalent to `release`.
+    fn from(ptr: Unique<'a, T, D>) -> Self {
+        ptr.release()
     }
 }
 
@@ -264,3 +1743,177 @@ impl<'a, T: ?Sized> From<&'a mut T> for Unique<'a, T, ()> {
         }
     }
 }
+
+#[macro_export]
+///Converts `Unique` into `Unique` of unsized type (e.g. trait object or slice), keeping lifetime and deleter.
+///
+///Works on stable Rust, where `CoerceUnsized` cannot be implemented.
+///Only unsizing coercion is allowed, therefore it is not possible to reinterpret type.
+///
+///```rust
+///use smart_ptr::{unsize, Unique};
+///
+///let mut value = 1u32;
+///let ptr = Unique::<u32, ()>::from(&mut value);
+///let ptr = unsize!(ptr => dyn core::fmt::Display);
+///assert_eq!(ptr.to_string(), "1");
+///
+///let mut array = [1u8, 2];
+///let ptr = Unique::<[u8; 2], ()>::from(&mut array);
+///let ptr = unsize!(ptr => [u8]);
+///assert_eq!(ptr.len(), 2);
+///```
+///
+///```rust,compile_fail
+///use smart_ptr::{unsize, Unique};
+///
+///let mut value = 1u32;
+///let ptr = Unique::<u32, ()>::from(&mut value);
+///let ptr = unsize!(ptr => u8);
+///```
+macro_rules! unsize {
+    ($ptr:expr => $ty:ty) => {
+        unsafe {
+            $crate::Unique::__unsize($ptr, |ptr| -> *mut $ty {
+                ptr
+            })
+        }
+    };
+}
+
+#[macro_export]
+///Asserts at compile time that pointer types have the same ABI as C pointer.
+///
+///Each type must have the same size and alignment as `*mut c_void`, while its `Option` must be
+///of the same size, using null as `None`.
+///
+///```rust
+///use smart_ptr::{assert_ffi_repr, Unique, UniqueConst};
+///
+///struct Foo;
+///
+///assert_ffi_repr!(Unique<'static, Foo, ()>, UniqueConst<'static, Foo, ()>);
+///```
+///
+///```rust,compile_fail
+///use smart_ptr::{assert_ffi_repr, Unique};
+///
+/////Slice pointer is fat, therefore it cannot be passed as C pointer
+///assert_ffi_repr!(Unique<'static, [u8], ()>);
+///```
+///
+///```rust,compile_fail
+///use smart_ptr::assert_ffi_repr;
+///
+/////Raw pointer has no null niche
+///assert_ffi_repr!(*mut u8);
+///```
+macro_rules! assert_ffi_repr {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            const _: () = {
+                assert!(core::mem::size_of::<$ty>() == core::mem::size_of::<*mut core::ffi::c_void>(), concat!("`", stringify!($ty), "` must have size of pointer"));
+                assert!(core::mem::align_of::<$ty>() == core::mem::align_of::<*mut core::ffi::c_void>(), concat!("`", stringify!($ty), "` must have alignment of pointer"));
+                assert!(core::mem::size_of::<Option<$ty>>() == core::mem::size_of::<$ty>(), concat!("`Option<", stringify!($ty), ">` must use null pointer as `None`"));
+            };
+        )+
+    };
+}
+
+#[macro_export]
+///Declares opaque handle to export Rust type to C, as `#[repr(transparent)]` wrapper over `Unique`.
+///
+///Handle is convertible from and into `Unique<'static, T, D>` and dereferences to `T`.
+///
+///Optionally it generates `extern "C"` functions:
+///
+///- `create` function with provided arguments, which returns handle constructed from expression;
+///- `destroy` function, which disposes of handle, accepting null as well.
+///
+///```rust
+///use smart_ptr::{declare_opaque, Deleter, Unique};
+///
+///pub struct Session {
+///    id: u32,
+///}
+///
+///pub struct SessionDeleter;
+///
+///impl Deleter for SessionDeleter {
+///    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+///        let _ = Box::from_raw(ptr as *mut Session);
+///    }
+///}
+///
+///declare_opaque! {
+///    ///Handle to the session.
+///    pub SessionHandle(Session, SessionDeleter);
+///    create = doc_session_create(id: u32) => unsafe {
+///        Unique::new(Box::into_raw(Box::new(Session { id })))
+///    };
+///    destroy = doc_session_destroy;
+///}
+///
+///let handle = doc_session_create(1);
+///assert_eq!(handle.id, 1);
+///doc_session_destroy(Some(handle));
+///doc_session_destroy(None);
+///```
+macro_rules! declare_opaque {
+    (
+        $(#[$attr:meta])*
+        $vis:vis $handle:ident($ty:ty, $deleter:ty);
+        $(create = $create:ident($($arg:ident: $arg_ty:ty),* $(,)?) => $ctor:expr;)?
+        $(destroy = $destroy:ident;)?
+    ) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        $vis struct $handle($crate::Unique<'static, $ty, $deleter>);
+
+        impl From<$crate::Unique<'static, $ty, $deleter>> for $handle {
+            #[inline(always)]
+            fn from(ptr: $crate::Unique<'static, $ty, $deleter>) -> Self {
+                Self(ptr)
+            }
+        }
+
+        impl From<$handle> for $crate::Unique<'static, $ty, $deleter> {
+            #[inline(always)]
+            fn from(handle: $handle) -> Self {
+                handle.0
+            }
+        }
+
+        impl core::ops::Deref for $handle {
+            type Target = $ty;
+
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl core::ops::DerefMut for $handle {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        $(
+            #[no_mangle]
+            ///Creates new handle.
+            pub extern "C" fn $create($($arg: $arg_ty),*) -> $handle {
+                $handle::from($ctor)
+            }
+        )?
+
+        $(
+            #[no_mangle]
+            ///Disposes of handle, doing nothing if it is null.
+            pub extern "C" fn $destroy(handle: Option<$handle>) {
+                drop(handle);
+            }
+        )?
+    };
+}
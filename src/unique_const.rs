@@ -0,0 +1,222 @@
+//!Read-only unique pointer implementation
+
+use core::{fmt, ptr};
+
+use crate::{Deleter, Unique};
+
+#[repr(transparent)]
+///Smart pointer, that owns and manages object via its pointer, while only providing shared access.
+///
+///Suitable for objects, that are immutable after creation, where mutable reference may alias
+///with references, held by library internally.
+///
+///On `Drop` it automatically disposes of pointer with provided deleter.
+///
+///```rust
+///use smart_ptr::UniqueConst;
+///
+///let value = 1u32;
+///let ptr = unsafe { UniqueConst::<u32, ()>::new(&value) };
+///assert_eq!(*ptr, 1);
+///```
+pub struct UniqueConst<'a, T: ?Sized, D: Deleter> {
+    inner: Unique<'a, T, D>,
+}
+
+impl<'a, T: ?Sized, D: Deleter> UniqueConst<'a, T, D> {
+    #[inline]
+    ///Creates new instance from raw pointer.
+    ///
+    ///# Panics
+    ///
+    ///- If pointer is null
+    pub unsafe fn new(ptr: *const T) -> Self {
+        assert!(!ptr.is_null());
+
+        Self::from_ptr_unchecked(ptr)
+    }
+
+    #[inline]
+    ///Creates instance from raw pointer, checking if pointer is null.
+    ///
+    ///Returns `None` if pointer is null.
+    pub unsafe fn from_ptr(ptr: *const T) -> Option<Self> {
+        match ptr.is_null() {
+            true => None,
+            false => Some(Self::from_ptr_unchecked(ptr)),
+        }
+    }
+
+    #[inline]
+    ///Creates instance from raw pointer, without checking if pointer is null.
+    ///
+    ///User must ensure that pointer is non-null
+    pub unsafe fn from_ptr_unchecked(ptr: *const T) -> Self {
+        Self {
+            inner: Unique::from_ptr_unchecked(ptr as *mut T),
+        }
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer.
+    pub fn get(&self) -> *const T {
+        self.inner.get()
+    }
+
+    #[inline(always)]
+    ///Gets reference to underlying data.
+    pub fn as_ref(&self) -> &T {
+        &self.inner
+    }
+
+    #[inline(always)]
+    ///Swaps underlying pointers between instances
+    pub fn swap(&mut self, other: &mut Self) {
+        self.inner.swap(&mut other.inner)
+    }
+
+    #[inline(always)]
+    ///Converts into mutable pointer.
+    ///
+    ///User must guarantee that object can be mutated, i.e. there are no other references to it.
+    pub unsafe fn into_mut(self) -> Unique<'a, T, D> {
+        self.inner
+    }
+
+    #[inline]
+    ///Releases the ownership and returns raw pointer, without dropping it.
+    pub fn release(self) -> ptr::NonNull<T> {
+        self.inner.release()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + Sync> UniqueConst<'static, T, crate::ArcDeleter> {
+    #[inline]
+    ///Takes ownership of one reference of `Arc`, which is released on `Drop`.
+    ///
+    ///```rust
+    ///use smart_ptr::{ArcDeleter, UniqueConst};
+    ///use std::sync::Arc;
+    ///
+    ///let shared = Arc::new(1u32);
+    ///let lent = UniqueConst::from_arc(shared.clone());
+    ///assert_eq!(Arc::strong_count(&shared), 2);
+    ///assert_eq!(*lent, 1);
+    ///drop(lent);
+    ///assert_eq!(Arc::strong_count(&shared), 1);
+    ///```
+    pub fn from_arc(ptr: alloc::sync::Arc<T>) -> Self {
+        unsafe {
+            Self::from_ptr_unchecked(alloc::sync::Arc::into_raw(ptr))
+        }
+    }
+
+    #[inline]
+    ///Converts back into `Arc`.
+    pub fn into_arc(self) -> alloc::sync::Arc<T> {
+        let ptr = self.release();
+        unsafe {
+            alloc::sync::Arc::from_raw(ptr.as_ptr() as *const T)
+        }
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> core::ops::Deref for UniqueConst<'a, T, D> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<'a, T: ?Sized, D: crate::CloneWith<T>> Clone for UniqueConst<'a, T, D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> crate::OwnedPtr for UniqueConst<'a, T, D> {
+    type Target = T;
+
+    #[inline(always)]
+    fn get(&self) -> *mut Self::Target {
+        UniqueConst::get(self) as *mut T
+    }
+
+    #[inline(always)]
+    fn release(self) -> ptr::NonNull<Self::Target> {
+        UniqueConst::release(self)
+    }
+
+    #[inline(always)]
+    unsafe fn from_non_null(ptr: ptr::NonNull<Self::Target>) -> Self {
+        Self::from_ptr_unchecked(ptr.as_ptr())
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> fmt::Pointer for UniqueConst<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.inner, fmt)
+    }
+}
+
+impl<'a, T: ?Sized + fmt::Debug, D: Deleter> fmt::Debug for UniqueConst<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_ref(), fmt)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<'a, T: ?Sized + defmt::Format, D: Deleter> defmt::Format for UniqueConst<'a, T, D> {
+    #[inline(always)]
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::Format::format(self.as_ref(), fmt)
+    }
+}
+
+impl<'a, T: ?Sized + fmt::Display, D: Deleter> fmt::Display for UniqueConst<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_ref(), fmt)
+    }
+}
+
+impl<'a, T: ?Sized + core::hash::Hash, D: Deleter> core::hash::Hash for UniqueConst<'a, T, D> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state);
+    }
+}
+
+impl<'a, T: ?Sized + PartialEq, D: Deleter> PartialEq<Self> for UniqueConst<'a, T, D> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<'a, T: ?Sized + Eq, D: Deleter> Eq for UniqueConst<'a, T, D> {
+}
+
+impl<'a, T: ?Sized, D: Deleter> From<Unique<'a, T, D>> for UniqueConst<'a, T, D> {
+    #[inline(always)]
+    fn from(ptr: Unique<'a, T, D>) -> Self {
+        ptr.into_const()
+    }
+}
+
+impl<'a, T: ?Sized> From<&'a T> for UniqueConst<'a, T, ()> {
+    #[inline]
+    fn from(ptr: &'a T) -> Self {
+        unsafe {
+            Self::from_ptr_unchecked(ptr)
+        }
+    }
+}
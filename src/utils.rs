@@ -0,0 +1,361 @@
+//!Scope guard utilities
+//!
+//!Helpers to run cleanup code at the end of scope, in the same RAII fashion as pointers.
+
+use core::{mem, marker};
+
+///Calls function on `Drop`.
+///
+///Function is called only once, therefore it can consume captured values.
+///
+///```rust
+///use smart_ptr::utils::CallOnDrop;
+///
+///let mut is_called = false;
+///{
+///    let _guard = CallOnDrop::new(|| is_called = true);
+///}
+///assert!(is_called);
+///
+///let (sender, receiver) = std::sync::mpsc::channel();
+///let message = String::from("done");
+///drop(CallOnDrop::new(move || sender.send(message).unwrap()));
+///assert_eq!(receiver.recv().unwrap(), "done");
+///```
+pub struct CallOnDrop<F: FnOnce()>(mem::ManuallyDrop<F>);
+
+impl<F: FnOnce()> CallOnDrop<F> {
+    #[inline(always)]
+    ///Creates new guard, which calls `cb` on `Drop`.
+    pub const fn new(cb: F) -> Self {
+        Self(mem::ManuallyDrop::new(cb))
+    }
+
+    #[inline]
+    ///Cancels guard, preventing function from being called.
+    pub fn cancel(self) {
+        drop(self.into_inner());
+    }
+
+    #[inline]
+    ///Cancels guard, returning function without calling it.
+    pub fn into_inner(self) -> F {
+        let mut this = mem::ManuallyDrop::new(self);
+        unsafe {
+            mem::ManuallyDrop::take(&mut this.0)
+        }
+    }
+}
+
+impl<F: FnOnce()> Drop for CallOnDrop<F> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        let cb = unsafe {
+            mem::ManuallyDrop::take(&mut self.0)
+        };
+        cb();
+    }
+}
+
+///Describes when guard should call its function.
+pub trait Strategy {
+    ///Returns whether function should be called on `Drop`.
+    fn should_run() -> bool;
+}
+
+///Strategy to always call function.
+pub enum Always {}
+
+impl Strategy for Always {
+    #[inline(always)]
+    fn should_run() -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "std")]
+///Strategy to call function only when scope is left due to panic.
+///
+///Without `std`, the same can be achieved by cancelling `Always` guard on success.
+pub enum OnUnwind {}
+
+#[cfg(feature = "std")]
+impl Strategy for OnUnwind {
+    #[inline(always)]
+    fn should_run() -> bool {
+        std::thread::panicking()
+    }
+}
+
+#[cfg(feature = "std")]
+///Strategy to call function only when scope is left normally, without panic.
+pub enum OnSuccess {}
+
+#[cfg(feature = "std")]
+impl Strategy for OnSuccess {
+    #[inline(always)]
+    fn should_run() -> bool {
+        !std::thread::panicking()
+    }
+}
+
+///Guard, which owns value and passes it to the function on `Drop`.
+///
+///Whether function is called, is determined by `Strategy`.
+///
+///Provides access to the value via `Deref` and `DerefMut`.
+///
+///In order to avoid conflicts with methods of the value, guard's methods are associated functions.
+///
+///```rust
+///use smart_ptr::utils::ScopeGuard;
+///
+///let mut output = Vec::new();
+///{
+///    let mut guard = ScopeGuard::new(&mut output, |output| output.push(2));
+///    guard.push(1);
+///}
+///assert_eq!(output, [1, 2]);
+///
+///let guard = ScopeGuard::new(3, |_| unreachable!());
+///assert_eq!(ScopeGuard::into_inner(guard), 3);
+///```
+pub struct ScopeGuard<T, F: FnOnce(T), S: Strategy = Always> {
+    value: mem::ManuallyDrop<T>,
+    cb: mem::ManuallyDrop<F>,
+    _strategy: marker::PhantomData<S>,
+}
+
+impl<T, F: FnOnce(T)> ScopeGuard<T, F> {
+    #[inline(always)]
+    ///Creates new guard, which passes `value` to `cb` on `Drop`.
+    pub const fn new(value: T, cb: F) -> Self {
+        Self::with_strategy(value, cb)
+    }
+}
+
+impl<T, F: FnOnce(T), S: Strategy> ScopeGuard<T, F, S> {
+    #[inline(always)]
+    ///Creates new guard, which passes `value` to `cb` on `Drop`, if allowed by `Strategy`.
+    pub const fn with_strategy(value: T, cb: F) -> Self {
+        Self {
+            value: mem::ManuallyDrop::new(value),
+            cb: mem::ManuallyDrop::new(cb),
+            _strategy: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Cancels guard, returning owned value without calling function.
+    pub fn into_inner(guard: Self) -> T {
+        let mut guard = mem::ManuallyDrop::new(guard);
+        unsafe {
+            mem::ManuallyDrop::drop(&mut guard.cb);
+            mem::ManuallyDrop::take(&mut guard.value)
+        }
+    }
+
+    #[inline]
+    ///Cancels guard, dropping owned value without calling function.
+    pub fn dismiss(guard: Self) {
+        drop(Self::into_inner(guard));
+    }
+}
+
+impl<T, F: FnOnce(T), S: Strategy> core::ops::Deref for ScopeGuard<T, F, S> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, F: FnOnce(T), S: Strategy> core::ops::DerefMut for ScopeGuard<T, F, S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T, F: FnOnce(T), S: Strategy> Drop for ScopeGuard<T, F, S> {
+    #[inline]
+    fn drop(&mut self) {
+        let (value, cb) = unsafe {
+            (mem::ManuallyDrop::take(&mut self.value), mem::ManuallyDrop::take(&mut self.cb))
+        };
+        if S::should_run() {
+            cb(value);
+        }
+    }
+}
+
+impl<T: core::fmt::Debug, F: FnOnce(T), S: Strategy> core::fmt::Debug for ScopeGuard<T, F, S> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&*self.value, fmt)
+    }
+}
+
+#[inline(always)]
+///Creates new guard, which passes `value` to `cb` on `Drop`.
+pub const fn guard<T, F: FnOnce(T)>(value: T, cb: F) -> ScopeGuard<T, F> {
+    ScopeGuard::new(value, cb)
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+///Creates new guard, which passes `value` to `cb` on `Drop` only when scope is left due to panic.
+///
+///```rust
+///use smart_ptr::utils::guard_on_unwind;
+///
+///let mut is_rolled_back = false;
+///{
+///    let _guard = guard_on_unwind(&mut is_rolled_back, |is_rolled_back| *is_rolled_back = true);
+///}
+///assert!(!is_rolled_back);
+///```
+pub const fn guard_on_unwind<T, F: FnOnce(T)>(value: T, cb: F) -> ScopeGuard<T, F, OnUnwind> {
+    ScopeGuard::with_strategy(value, cb)
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+///Creates new guard, which passes `value` to `cb` on `Drop` only when scope is left normally.
+pub const fn guard_on_success<T, F: FnOnce(T)>(value: T, cb: F) -> ScopeGuard<T, F, OnSuccess> {
+    ScopeGuard::with_strategy(value, cb)
+}
+
+///Deletes every pointer of `iter` with deleter `D`, skipping null pointers.
+///
+///Useful to clean up partially constructed arrays of objects, when initialization fails midway.
+///
+///## Safety
+///
+///Every non-null pointer must be valid for `D`, and must not be used after this call.
+///
+///```rust
+///use smart_ptr::utils::delete_all;
+///use smart_ptr::DropInPlace;
+///
+///let mut values = [core::mem::ManuallyDrop::new(String::from("1")), core::mem::ManuallyDrop::new(String::from("2"))];
+///let ptrs = [&mut *values[0] as *mut String, core::ptr::null_mut(), &mut *values[1] as *mut String];
+///unsafe {
+///    delete_all::<String, DropInPlace, _>(ptrs);
+///}
+///```
+pub unsafe fn delete_all<T: ?Sized, D: crate::Deleter, I: IntoIterator<Item = *mut T>>(iter: I) {
+    for ptr in iter {
+        if !ptr.is_null() {
+            D::delete_with_layout::<T>(ptr, core::alloc::Layout::for_value(&*ptr));
+        }
+    }
+}
+
+///Iterator over owned pointers, which deletes all remaining ones on `Drop` with deleter `D`.
+///
+///Null pointers are skipped.
+///Pointers, that are yielded by iterator, are owned by user, while the rest is deleted even if
+///scope is left due to panic.
+///
+///```rust
+///use smart_ptr::utils::DeleteGuard;
+///use smart_ptr::DropInPlace;
+///
+///let mut values = [core::mem::ManuallyDrop::new(String::from("1")), core::mem::ManuallyDrop::new(String::from("2"))];
+///let ptrs = [&mut *values[0] as *mut String, &mut *values[1] as *mut String];
+///let mut guard = unsafe {
+///    DeleteGuard::<String, DropInPlace, _>::new(ptrs)
+///};
+///
+///let first = guard.next().expect("to have first");
+///assert_eq!(unsafe { &*first }, "1");
+/////Second string is dropped with guard.
+///drop(guard);
+///unsafe {
+///    core::mem::ManuallyDrop::drop(&mut values[0]);
+///}
+///```
+pub struct DeleteGuard<T: ?Sized, D: crate::Deleter, I: Iterator<Item = *mut T>> {
+    iter: mem::ManuallyDrop<I>,
+    _traits: marker::PhantomData<D>,
+}
+
+impl<T: ?Sized, D: crate::Deleter, I: Iterator<Item = *mut T>> DeleteGuard<T, D, I> {
+    #[inline(always)]
+    ///Creates new guard, taking ownership of all pointers within `iter`.
+    ///
+    ///## Safety
+    ///
+    ///Every non-null pointer must be valid for `D`.
+    pub unsafe fn new<IT: IntoIterator<IntoIter = I>>(iter: IT) -> Self {
+        Self {
+            iter: mem::ManuallyDrop::new(iter.into_iter()),
+            _traits: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Cancels guard, returning remaining pointers without deleting them.
+    pub fn into_inner(self) -> I {
+        let mut this = mem::ManuallyDrop::new(self);
+        unsafe {
+            mem::ManuallyDrop::take(&mut this.iter)
+        }
+    }
+}
+
+impl<T: ?Sized, D: crate::Deleter, I: Iterator<Item = *mut T>> Iterator for DeleteGuard<T, D, I> {
+    type Item = *mut T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T: ?Sized, D: crate::Deleter, I: Iterator<Item = *mut T>> Drop for DeleteGuard<T, D, I> {
+    #[inline]
+    fn drop(&mut self) {
+        let iter = unsafe {
+            mem::ManuallyDrop::take(&mut self.iter)
+        };
+        unsafe {
+            delete_all::<T, D, I>(iter);
+        }
+    }
+}
+
+#[macro_export]
+///Executes code at the end of the current scope.
+///
+///Built on top of [CallOnDrop](utils/struct.CallOnDrop.html), therefore code is executed even if
+///scope is left due to panic.
+///
+///Multiple deferred blocks are executed in reverse order.
+///
+///```rust
+///use smart_ptr::defer;
+///
+///let output = core::cell::RefCell::new(Vec::new());
+///{
+///    defer! {
+///        output.borrow_mut().push(2);
+///    }
+///    defer!(output.borrow_mut().push(1));
+///}
+///assert_eq!(*output.borrow(), [1, 2]);
+///```
+macro_rules! defer {
+    ($($code:tt)*) => {
+        let _guard = $crate::utils::CallOnDrop::new(|| {
+            $($code)*
+        });
+    };
+}
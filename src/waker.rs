@@ -0,0 +1,137 @@
+//!Conversion of owned pointers into `Waker`.
+//!
+//!Vtable of the waker is generated for each pointer type, routing `clone` and `drop` through
+//!pointer's deleter or reference count.
+//!
+//!```rust
+//!use smart_ptr::intrusive::{IntrusiveShared, RefCounted, RefCounter};
+//!use smart_ptr::waker::Wake;
+//!use smart_ptr::Deleter;
+//!
+//!use core::sync::atomic::{AtomicUsize, Ordering};
+//!
+//!pub struct BoxDeleter;
+//!
+//!impl Deleter for BoxDeleter {
+//!    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+//!        drop(Box::from_raw(ptr));
+//!    }
+//!}
+//!
+//!#[derive(Default)]
+//!struct Task {
+//!    counter: RefCounter,
+//!    wakes: AtomicUsize,
+//!}
+//!
+//!unsafe impl RefCounted for Task {
+//!    fn inc(&self) {
+//!        self.counter.inc()
+//!    }
+//!
+//!    fn dec(&self) -> bool {
+//!        self.counter.dec()
+//!    }
+//!}
+//!
+//!impl Wake for Task {
+//!    fn wake(&self) {
+//!        self.wakes.fetch_add(1, Ordering::SeqCst);
+//!    }
+//!}
+//!
+//!let task = unsafe { IntrusiveShared::<Task, BoxDeleter>::new(Box::into_raw(Box::new(Task::default()))) };
+//!let waker = task.clone().into_waker();
+//!waker.wake_by_ref();
+//!waker.clone().wake();
+//!assert_eq!(task.wakes.load(Ordering::SeqCst), 2);
+//!
+//!drop(waker);
+//!assert_eq!(task.counter.get(), 1);
+//!```
+
+use core::marker;
+use core::task::{RawWaker, RawWakerVTable};
+
+use crate::{CloneWith, Deleter, Unique};
+use crate::intrusive::{IntrusiveShared, RefCounted};
+
+///Describes how to wake up task, which is owned by waker.
+///
+///Waker can be sent to other threads, therefore implementation must be thread safe.
+pub trait Wake: Send + Sync {
+    ///Wakes up task.
+    fn wake(&self);
+}
+
+//Pointer, which can be stored within `RawWaker`.
+pub(crate) trait WakerPtr: core::ops::Deref {
+    fn into_raw(self) -> *const ();
+    unsafe fn from_raw(ptr: *const ()) -> Self;
+    //Creates new pointer from borrowed one, without taking ownership of it.
+    unsafe fn clone_raw(ptr: *const ()) -> Self;
+}
+
+impl<W: Wake, D: CloneWith<W>> WakerPtr for Unique<'static, W, D> {
+    #[inline(always)]
+    fn into_raw(self) -> *const () {
+        self.release().as_ptr() as *const ()
+    }
+
+    #[inline(always)]
+    unsafe fn from_raw(ptr: *const ()) -> Self {
+        Self::from_ptr_unchecked(ptr as *mut W)
+    }
+
+    #[inline(always)]
+    unsafe fn clone_raw(ptr: *const ()) -> Self {
+        Self::new(D::clone_ptr(ptr as *const W))
+    }
+}
+
+impl<W: Wake + RefCounted, D: Deleter> WakerPtr for IntrusiveShared<W, D> {
+    #[inline(always)]
+    fn into_raw(self) -> *const () {
+        self.release().as_ptr() as *const ()
+    }
+
+    #[inline(always)]
+    unsafe fn from_raw(ptr: *const ()) -> Self {
+        Self::from_ptr_unchecked(ptr as *mut W)
+    }
+
+    #[inline(always)]
+    unsafe fn clone_raw(ptr: *const ()) -> Self {
+        (*(ptr as *const W)).inc();
+        Self::from_ptr_unchecked(ptr as *mut W)
+    }
+}
+
+struct VTable<P>(marker::PhantomData<P>);
+
+impl<P: WakerPtr + 'static> VTable<P> where P::Target: Wake + Sized {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(Self::clone, Self::wake, Self::wake_by_ref, Self::drop);
+
+    unsafe fn clone(ptr: *const ()) -> RawWaker {
+        raw_waker(P::clone_raw(ptr))
+    }
+
+    unsafe fn wake(ptr: *const ()) {
+        let ptr = P::from_raw(ptr);
+        ptr.wake();
+    }
+
+    unsafe fn wake_by_ref(ptr: *const ()) {
+        //Waker retains ownership, therefore value is only borrowed.
+        (*(ptr as *const P::Target)).wake();
+    }
+
+    unsafe fn drop(ptr: *const ()) {
+        drop(P::from_raw(ptr));
+    }
+}
+
+#[inline]
+pub(crate) fn raw_waker<P: WakerPtr + 'static>(ptr: P) -> RawWaker where P::Target: Wake + Sized {
+    RawWaker::new(ptr.into_raw(), &VTable::<P>::VTABLE)
+}
@@ -0,0 +1,166 @@
+//!Owner with non-owning observers, that can detect its destruction.
+
+use core::{mem, fmt, ptr};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use alloc::sync::Arc;
+
+use crate::{Deleter, Unique};
+
+///Owner of the pointer, which hands out [Observer](struct.Observer.html) handles.
+///
+///Each time owned pointer is dropped, replaced or released, generation of the owner is incremented,
+///invalidating all observers created before.
+///
+///```rust
+///use smart_ptr::unique::Global;
+///use smart_ptr::watch::Watched;
+///
+///let owner = Watched::new(Global::boxed(1u32));
+///let observer = owner.observe();
+///assert_eq!(observer.get().map(|ptr| unsafe { *ptr.as_ptr() }), Some(1));
+///
+///drop(owner);
+///assert!(observer.get().is_none());
+///```
+pub struct Watched<'a, T: ?Sized, D: Deleter> {
+    inner: Unique<'a, T, D>,
+    generation: Arc<AtomicUsize>,
+}
+
+impl<'a, T: ?Sized, D: Deleter> Watched<'a, T, D> {
+    #[inline]
+    ///Creates new owner of the pointer.
+    pub fn new(inner: Unique<'a, T, D>) -> Self {
+        Self {
+            inner,
+            generation: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    #[inline]
+    ///Creates observer of current pointer.
+    pub fn observe(&self) -> Observer<T> {
+        Observer {
+            ptr: self.inner.as_non_null(),
+            generation: self.generation.load(Ordering::Acquire),
+            state: self.generation.clone(),
+        }
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer.
+    pub fn get(&self) -> *mut T {
+        self.inner.get()
+    }
+
+    #[inline]
+    ///Replaces owned pointer, invalidating existing observers, and returns previous one.
+    pub fn replace(&mut self, inner: Unique<'a, T, D>) -> Unique<'a, T, D> {
+        self.invalidate();
+        mem::replace(&mut self.inner, inner)
+    }
+
+    #[inline]
+    ///Returns owned pointer, invalidating existing observers.
+    pub fn into_inner(self) -> Unique<'a, T, D> {
+        self.invalidate();
+        let this = mem::ManuallyDrop::new(self);
+        unsafe {
+            drop(ptr::read(&this.generation));
+            ptr::read(&this.inner)
+        }
+    }
+
+    #[inline(always)]
+    fn invalidate(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> Drop for Watched<'a, T, D> {
+    #[inline]
+    fn drop(&mut self) {
+        self.invalidate();
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> core::ops::Deref for Watched<'a, T, D> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> core::ops::DerefMut for Watched<'a, T, D> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<'a, T: ?Sized + fmt::Debug, D: Deleter> fmt::Debug for Watched<'a, T, D> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, fmt)
+    }
+}
+
+impl<'a, T: ?Sized, D: Deleter> From<Unique<'a, T, D>> for Watched<'a, T, D> {
+    #[inline(always)]
+    fn from(inner: Unique<'a, T, D>) -> Self {
+        Self::new(inner)
+    }
+}
+
+///Non-owning handle to the pointer of [Watched](struct.Watched.html).
+///
+///It never dereferences pointer by itself, but allows to check whether owner still holds it.
+pub struct Observer<T: ?Sized> {
+    ptr: ptr::NonNull<T>,
+    generation: usize,
+    state: Arc<AtomicUsize>,
+}
+
+impl<T: ?Sized> Observer<T> {
+    #[inline]
+    ///Returns whether observed pointer is still owned.
+    pub fn is_alive(&self) -> bool {
+        self.state.load(Ordering::Acquire) == self.generation
+    }
+
+    #[inline]
+    ///Returns observed pointer, if it is still owned.
+    ///
+    ///Note that owner may drop pointer right after check, therefore it is up to user to
+    ///synchronize access to the pointer.
+    pub fn get(&self) -> Option<ptr::NonNull<T>> {
+        match self.is_alive() {
+            true => Some(self.ptr),
+            false => None,
+        }
+    }
+}
+
+impl<T: ?Sized> Clone for Observer<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            ptr: self.ptr,
+            generation: self.generation,
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Observer<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Observer").field("ptr", &self.ptr).field("is_alive", &self.is_alive()).finish()
+    }
+}
+
+unsafe impl<T: ?Sized + Sync> Send for Observer<T> {}
+
+unsafe impl<T: ?Sized + Sync> Sync for Observer<T> {}
@@ -0,0 +1,245 @@
+//!Owned wide (UTF-16) string pointer, dominant string representation of Win32 APIs.
+
+use core::{fmt, ptr};
+use core::fmt::Write;
+
+use crate::{Deleter, Unique};
+
+#[cfg(windows)]
+mod sys {
+    use core::ffi::c_void;
+
+    #[link(name = "ole32")]
+    extern "system" {
+        pub fn CoTaskMemFree(ptr: *mut c_void);
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn LocalFree(ptr: *mut c_void) -> *mut c_void;
+    }
+}
+
+#[cfg(windows)]
+#[derive(Default)]
+///Deleter, which frees memory using `CoTaskMemFree`, without invoking destructor.
+pub struct CoTaskMemDeleter;
+
+#[cfg(windows)]
+impl Deleter for CoTaskMemDeleter {
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        sys::CoTaskMemFree(ptr as *mut core::ffi::c_void)
+    }
+}
+
+#[cfg(windows)]
+#[derive(Default)]
+///Deleter, which frees memory using `LocalFree`, without invoking destructor.
+pub struct LocalDeleter;
+
+#[cfg(windows)]
+impl Deleter for LocalDeleter {
+    #[inline]
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        sys::LocalFree(ptr as *mut core::ffi::c_void);
+    }
+}
+
+///Null-terminated UTF-16 string, that owns its buffer.
+///
+///On `Drop` it disposes of buffer with provided deleter, which receives slice of all characters,
+///including null terminator.
+///
+///```rust
+///use smart_ptr::WideStrPtr;
+///
+///let mut buffer = [0x74u16, 0x65, 0x73, 0x74, 0];
+///let string = unsafe { WideStrPtr::<()>::new(buffer.as_mut_ptr()) };
+///assert_eq!(string.len(), 4);
+///assert_eq!(string.to_string(), "test");
+///```
+pub struct WideStrPtr<'a, D: Deleter> {
+    inner: Unique<'a, [u16], D>,
+}
+
+#[cfg(feature = "alloc")]
+impl WideStrPtr<'static, crate::GlobalDeleter> {
+    ///Creates new instance, encoding `text` into buffer, allocated by global allocator.
+    ///
+    ///Note that `text` is truncated on first null character.
+    pub fn encode(text: &str) -> Self {
+        Self::from_units(text.encode_utf16())
+    }
+
+    fn from_units<I: Iterator<Item = u16>>(units: I) -> Self {
+        let mut buffer = alloc::vec::Vec::new();
+        buffer.extend(units.take_while(|unit| *unit != 0));
+        buffer.push(0);
+
+        Self {
+            inner: crate::unique::Global::from_vec(buffer),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<&std::ffi::OsStr> for WideStrPtr<'static, crate::GlobalDeleter> {
+    ///Encodes platform string as UTF-16.
+    ///
+    ///On non-Windows platforms string is encoded lossily.
+    fn from(text: &std::ffi::OsStr) -> Self {
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStrExt;
+            Self::from_units(text.encode_wide())
+        }
+        #[cfg(not(windows))]
+        {
+            Self::from_units(text.to_string_lossy().encode_utf16())
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&str> for WideStrPtr<'static, crate::GlobalDeleter> {
+    #[inline(always)]
+    fn from(text: &str) -> Self {
+        Self::encode(text)
+    }
+}
+
+impl<'a, D: Deleter> WideStrPtr<'a, D> {
+    #[inline]
+    ///Creates new instance from pointer to null-terminated string.
+    ///
+    ///# Panics
+    ///
+    ///- If pointer is null
+    pub unsafe fn new(ptr: *mut u16) -> Self {
+        assert!(!ptr.is_null());
+
+        Self::from_ptr_unchecked(ptr)
+    }
+
+    #[inline]
+    ///Creates instance from pointer to null-terminated string, checking if pointer is null.
+    ///
+    ///Returns `None` if pointer is null.
+    pub unsafe fn from_ptr(ptr: *mut u16) -> Option<Self> {
+        match ptr.is_null() {
+            true => None,
+            false => Some(Self::from_ptr_unchecked(ptr)),
+        }
+    }
+
+    #[inline]
+    ///Creates instance from pointer to null-terminated string, without checking if pointer is null.
+    ///
+    ///User must ensure that pointer is non-null
+    pub unsafe fn from_ptr_unchecked(ptr: *mut u16) -> Self {
+        let mut len = 0;
+        while *ptr.add(len) != 0 {
+            len += 1;
+        }
+
+        Self {
+            inner: Unique::from_ptr_unchecked(ptr::slice_from_raw_parts_mut(ptr, len + 1)),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of UTF-16 code units, excluding null terminator.
+    pub fn len(&self) -> usize {
+        self.inner.len() - 1
+    }
+
+    #[inline(always)]
+    ///Returns whether string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer to null-terminated string.
+    pub fn as_ptr(&self) -> *const u16 {
+        self.inner.get() as *const u16
+    }
+
+    #[inline(always)]
+    ///Gets underlying raw pointer to null-terminated string.
+    pub fn as_mut_ptr(&mut self) -> *mut u16 {
+        self.inner.get() as *mut u16
+    }
+
+    #[inline(always)]
+    ///Gets UTF-16 code units, excluding null terminator.
+    pub fn as_slice(&self) -> &[u16] {
+        &self.inner[..self.len()]
+    }
+
+    #[inline(always)]
+    ///Gets UTF-16 code units, including null terminator.
+    pub fn as_slice_with_nul(&self) -> &[u16] {
+        &self.inner
+    }
+
+    #[inline]
+    ///Returns iterator over characters, replacing invalid UTF-16 with `REPLACEMENT_CHARACTER`.
+    pub fn chars_lossy(&self) -> impl Iterator<Item = char> + '_ {
+        char::decode_utf16(self.as_slice().iter().cloned()).map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    #[cfg(feature = "std")]
+    ///Converts into platform string.
+    ///
+    ///On non-Windows platforms invalid UTF-16 is replaced with `REPLACEMENT_CHARACTER`.
+    pub fn to_os_string(&self) -> std::ffi::OsString {
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStringExt;
+            std::ffi::OsString::from_wide(self.as_slice())
+        }
+        #[cfg(not(windows))]
+        {
+            self.chars_lossy().collect::<std::string::String>().into()
+        }
+    }
+
+    #[inline]
+    ///Releases the ownership and returns raw pointer to null-terminated string, without dropping it.
+    pub fn release(self) -> ptr::NonNull<u16> {
+        self.inner.release().cast()
+    }
+}
+
+impl<'a, D: Deleter> fmt::Display for WideStrPtr<'a, D> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ch in self.chars_lossy() {
+            fmt.write_char(ch)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, D: Deleter> fmt::Debug for WideStrPtr<'a, D> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_char('"')?;
+        for ch in self.chars_lossy() {
+            for ch in ch.escape_debug() {
+                fmt.write_char(ch)?;
+            }
+        }
+        fmt.write_char('"')
+    }
+}
+
+impl<'a, D: Deleter> PartialEq<Self> for WideStrPtr<'a, D> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<'a, D: Deleter> Eq for WideStrPtr<'a, D> {
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "alloc")]
+
+use smart_ptr::unique::Global;
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn should_allocate_with_alignment() {
+    static DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct Droppy(u8);
+
+    impl Drop for Droppy {
+        fn drop(&mut self) {
+            DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    for align in [1, 2, 8, 64, 4096] {
+        let ptr = Global::boxed_aligned(Droppy(1), align);
+        assert_eq!(ptr.get() as usize % align, 0);
+        assert_eq!(ptr.0, 1);
+    }
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 5);
+
+    let ptr = Global::boxed_aligned(1u64, 1);
+    assert_eq!(ptr.get() as usize % core::mem::align_of::<u64>(), 0);
+    assert_eq!(*ptr, 1);
+
+    let ptr = Global::boxed_aligned((), 32);
+    assert_eq!(ptr.get() as usize % 32, 0);
+}
+
+#[test]
+#[should_panic(expected = "Alignment must be power of two")]
+fn should_panic_on_invalid_alignment() {
+    Global::boxed_aligned(0u8, 3);
+}
+
+#[test]
+fn should_allocate_cache_aligned() {
+    use smart_ptr::aligned::CachePadded;
+
+    let align = core::mem::align_of::<CachePadded<u8>>();
+    assert!(align >= 64);
+    assert_eq!(core::mem::size_of::<CachePadded<u8>>(), align);
+
+    let mut ptr = Global::boxed_cache_aligned(1u8);
+    assert_eq!(ptr.get() as usize % align, 0);
+    **ptr = 2;
+    assert_eq!(ptr.into_boxed().into_inner(), 2);
+}
+
+#[test]
+fn should_allocate_pages() {
+    use smart_ptr::aligned::page_size;
+
+    let page_size = page_size();
+    assert!(page_size.is_power_of_two());
+
+    let ptr = Global::boxed_pages([1u8; 10]);
+    assert_eq!(ptr.get() as usize % page_size, 0);
+    assert_eq!(*ptr, [1u8; 10]);
+
+    let ptr = Global::boxed_pages(());
+    assert_eq!(ptr.get() as usize % page_size, 0);
+
+    let buffer = Global::<[u64]>::new_zeroed_pages(page_size);
+    assert_eq!(buffer.get() as *mut u8 as usize % page_size, 0);
+    let buffer = unsafe { buffer.assume_init() };
+    assert_eq!(buffer.len(), page_size);
+    assert!(buffer.iter().all(|elem| *elem == 0));
+}
+
+#[test]
+fn should_allocate_aligned_buffer() {
+    use smart_ptr::aligned::AlignedBuffer;
+
+    for align in [1, 16, 4096] {
+        let mut buffer = AlignedBuffer::new(100, align);
+        assert_eq!(buffer.len(), 100);
+        assert_eq!(buffer.align(), align);
+        assert_eq!(buffer.as_ptr() as usize & (align - 1), 0);
+        assert!(buffer.iter().all(|byte| *byte == 0));
+        buffer.copy_from_slice(&[7; 100]);
+        assert!(buffer.iter().all(|byte| *byte == 7));
+    }
+
+    let empty = AlignedBuffer::new(0, 64);
+    assert!(empty.is_empty());
+    assert_eq!(empty.as_ptr() as usize & 63, 0);
+
+    assert!(AlignedBuffer::try_new(usize::MAX, 64).is_err());
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "alloc")]
+
+use smart_ptr::Unique;
+use smart_ptr::arena::Arena;
+
+use std::rc::Rc;
+
+#[test]
+fn should_run_destructor_without_freeing() {
+    let counter = Rc::new(());
+    let mut arena = Arena::new();
+
+    let value = Unique::boxed_in_arena(&arena, counter.clone());
+    assert_eq!(Rc::strong_count(&counter), 2);
+    drop(value);
+    assert_eq!(Rc::strong_count(&counter), 1);
+    assert_ne!(arena.capacity(), 0);
+
+    arena.reset();
+    assert_eq!(arena.capacity(), 0);
+}
+
+#[test]
+fn should_align_values_and_grow() {
+    #[repr(align(64))]
+    struct Aligned(u8);
+
+    let arena = Arena::with_chunk_size(16);
+    let byte = arena.boxed(1u8);
+    let aligned = arena.boxed(Aligned(2));
+    let empty = arena.boxed(());
+    assert_eq!(*byte, 1);
+    assert_eq!(aligned.0, 2);
+    assert_eq!(aligned.get() as usize & 63, 0);
+    assert_eq!(*empty, ());
+
+    let text = arena.copy_str("arena allocated text, longer than chunk");
+    assert_eq!(&*text, "arena allocated text, longer than chunk");
+    let numbers = arena.copy_slice(&[1u64, 2, 3]);
+    assert_eq!(&*numbers, &[1, 2, 3]);
+    assert_eq!(numbers.get() as *mut u64 as usize & 7, 0);
+}
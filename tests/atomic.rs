@@ -0,0 +1,62 @@
+use smart_ptr::{AtomicUnique, Unique};
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static DELETE_COUNT: AtomicUsize = AtomicUsize::new(0);
+struct CountDeleter;
+
+impl smart_ptr::Deleter for CountDeleter {
+    unsafe fn delete<T: ?Sized>(_: *mut T) {
+        DELETE_COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn should_swap_store_and_take() {
+    let mut first = 1u32;
+    let mut second = 2u32;
+    let mut third = 3u32;
+
+    let first = unsafe { Unique::<_, CountDeleter>::new(&mut first) };
+    let second = unsafe { Unique::<_, CountDeleter>::new(&mut second) };
+    let third = unsafe { Unique::<_, CountDeleter>::new(&mut third) };
+
+    let mut atomic = AtomicUnique::empty();
+    assert!(atomic.is_none());
+    assert!(atomic.swap(Some(first)).is_none());
+    assert_eq!(atomic.get_mut(), Some(&mut 1));
+
+    atomic.store(Some(second));
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 1);
+
+    let third = atomic.compare_exchange(core::ptr::null_mut(), Some(third)).unwrap_err();
+    let current = atomic.as_ptr();
+    let second = atomic.compare_exchange(current, third).expect("to exchange").expect("to have value");
+    assert_eq!(*second, 2);
+    drop(second);
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 2);
+
+    assert_eq!(*atomic.take().expect("to have value"), 3);
+    assert!(atomic.is_none());
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 3);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_publish_across_threads() {
+    let atomic = std::sync::Arc::new(AtomicUnique::from(smart_ptr::unique::Global::boxed(0usize)));
+
+    let threads = (1..=4).map(|idx| {
+        let atomic = atomic.clone();
+        std::thread::spawn(move || {
+            atomic.swap(Some(smart_ptr::unique::Global::boxed(idx))).expect("to have value");
+        })
+    }).collect::<Vec<_>>();
+
+    for thread in threads {
+        thread.join().expect("to finish");
+    }
+
+    let value = atomic.take().expect("to have value");
+    assert!((1..=4).contains(&*value));
+}
@@ -0,0 +1,35 @@
+use smart_ptr::{Unique, UniqueCell};
+
+#[test]
+fn should_check_borrows_at_runtime() {
+    let mut value = String::from("cell");
+    let mut cell = UniqueCell::new(Unique::<String, ()>::from(&mut value));
+
+    {
+        let first = cell.borrow();
+        let second = cell.borrow();
+        assert_eq!(*first, *second);
+        assert!(cell.try_borrow_mut().is_none());
+    }
+
+    {
+        let mut value = cell.borrow_mut();
+        value.push_str(" changed");
+        assert!(cell.try_borrow().is_none());
+        assert!(cell.try_borrow_mut().is_none());
+        assert_eq!(format!("{:?}", cell), "UniqueCell { value: <borrowed> }");
+    }
+
+    assert_eq!(format!("{:?}", cell), "UniqueCell { value: \"cell changed\" }");
+    cell.get_mut().push('!');
+    assert_eq!(*cell.into_inner(), "cell changed!");
+}
+
+#[test]
+#[should_panic(expected = "UniqueCell is already mutably borrowed")]
+fn should_panic_on_conflicting_borrow() {
+    let mut value = 0u32;
+    let cell = UniqueCell::new(Unique::<u32, ()>::from(&mut value));
+    let _guard = cell.borrow_mut();
+    cell.borrow();
+}
@@ -0,0 +1,61 @@
+use smart_ptr::com::{Interface, ComPtr};
+
+use core::cell::Cell;
+
+struct Object {
+    count: Cell<u32>,
+    is_destroyed: Cell<bool>,
+}
+
+unsafe impl Interface for Object {
+    unsafe fn add_ref(this: *mut Self) {
+        let this = &*this;
+        this.count.set(this.count.get() + 1);
+    }
+
+    unsafe fn release(this: *mut Self) {
+        let this = &*this;
+        this.count.set(this.count.get() - 1);
+        if this.count.get() == 0 {
+            this.is_destroyed.set(true);
+        }
+    }
+}
+
+#[test]
+fn should_add_ref_on_clone_and_release_on_drop() {
+    let mut object = Object {
+        count: Cell::new(1),
+        is_destroyed: Cell::new(false),
+    };
+    let object_ptr = &mut object as *mut Object;
+
+    {
+        let ptr = unsafe { ComPtr::new(object_ptr) };
+        let clone = ptr.clone();
+        assert_eq!(ptr, clone);
+        assert_eq!(ptr.count.get(), 2);
+        drop(ptr);
+        assert_eq!(clone.count.get(), 1);
+        assert!(!clone.is_destroyed.get());
+    }
+
+    assert_eq!(object.count.get(), 0);
+    assert!(object.is_destroyed.get());
+}
+
+#[test]
+fn should_add_ref_on_borrow() {
+    let mut object = Object {
+        count: Cell::new(1),
+        is_destroyed: Cell::new(false),
+    };
+
+    let ptr = unsafe { ComPtr::from_ptr_borrowed(&mut object as *mut Object) }.expect("Non-null");
+    assert_eq!(ptr.count.get(), 2);
+    drop(ptr);
+    assert_eq!(object.count.get(), 1);
+    assert!(!object.is_destroyed.get());
+
+    assert!(unsafe { ComPtr::<Object>::from_ptr(core::ptr::null_mut()) }.is_none());
+}
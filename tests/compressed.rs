@@ -0,0 +1,51 @@
+use smart_ptr::compressed::{CompressedPtr, CompressedUnique, PtrBase};
+use smart_ptr::DropInPlace;
+
+use core::mem;
+use core::ptr;
+
+struct Region;
+
+static mut REGION: [mem::MaybeUninit<String>; 4] = [const { mem::MaybeUninit::uninit() }; 4];
+
+impl PtrBase for Region {
+    fn base() -> *mut u8 {
+        ptr::addr_of_mut!(REGION) as *mut u8
+    }
+}
+
+#[test]
+fn should_compress_pointer_within_region() {
+    assert_eq!(mem::size_of::<CompressedPtr<String, Region>>(), 4);
+    assert_eq!(mem::size_of::<Option<CompressedUnique<String, Region, DropInPlace>>>(), 4);
+
+    let null = CompressedPtr::<String, Region>::new(ptr::null_mut()).expect("to compress null");
+    assert!(null.is_null());
+    assert_eq!(null, CompressedPtr::default());
+    assert!(null.get().is_null());
+
+    let base = Region::base() as *mut String;
+    let first = CompressedPtr::<String, Region>::new(base).expect("to compress base");
+    assert_eq!(first.offset(), Some(0));
+    assert_eq!(first.get(), base);
+
+    let below = (Region::base() as usize - 1) as *mut String;
+    assert!(CompressedPtr::<String, Region>::new(below).is_none());
+    let far = Region::base().wrapping_add(u32::MAX as usize + 1) as *mut String;
+    assert!(CompressedPtr::<String, Region>::new(far).is_none());
+}
+
+#[test]
+fn should_delete_compressed_unique() {
+    let slot = unsafe { ptr::addr_of_mut!(REGION[1]) } as *mut String;
+    unsafe {
+        slot.write(String::from("compressed"));
+    }
+
+    let mut value = unsafe { CompressedUnique::<String, Region, DropInPlace>::new(slot) };
+    assert_eq!(value.get(), slot);
+    assert_eq!(value.as_compressed().offset(), Some(mem::size_of::<String>() as u32));
+    value.push_str(" value");
+    assert_eq!(*value, "compressed value");
+    drop(value);
+}
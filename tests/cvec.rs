@@ -0,0 +1,101 @@
+use smart_ptr::CVec;
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn should_pass_length_to_deleter() {
+    static DELETED_LEN: AtomicUsize = AtomicUsize::new(0);
+    pub struct LenDeleter;
+
+    impl smart_ptr::Deleter for LenDeleter {
+        unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+            DELETED_LEN.store(core::mem::size_of_val(&*ptr), Ordering::SeqCst);
+        }
+    }
+
+    let mut storage = [1u16, 2, 3];
+    let buffer = unsafe { CVec::<u16, LenDeleter>::from_raw(storage.as_mut_ptr(), 2) };
+    assert_eq!(*buffer, [1, 2]);
+    assert_eq!(buffer.capacity(), 2);
+    drop(buffer);
+    assert_eq!(DELETED_LEN.load(Ordering::SeqCst), 4);
+
+    let buffer = unsafe { CVec::<u16, LenDeleter>::from_raw(core::ptr::null_mut(), 0) };
+    assert!(buffer.is_empty());
+    assert_eq!(*buffer, []);
+    DELETED_LEN.store(0, Ordering::SeqCst);
+    drop(buffer);
+    assert_eq!(DELETED_LEN.load(Ordering::SeqCst), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_reconstitute_vec() {
+    use smart_ptr::cvec::VecDeleter;
+
+    let mut vec = Vec::with_capacity(10);
+    vec.push(String::from("test"));
+    let buffer = CVec::<_, VecDeleter>::from_vec(vec);
+    assert_eq!(buffer.len(), 1);
+    assert_eq!(buffer.capacity(), 10);
+    assert_eq!(buffer[0], "test");
+
+    let (ptr, len, capacity) = buffer.into_raw_parts();
+    let buffer = unsafe { CVec::<String, VecDeleter>::from_raw_parts(ptr, len, capacity) };
+    assert_eq!(buffer[0], "test");
+}
+
+#[test]
+fn should_delete_buffer_with_delete_slice() {
+    static SLICE_LEN: AtomicUsize = AtomicUsize::new(0);
+    static DELETE_COUNT: AtomicUsize = AtomicUsize::new(0);
+    pub struct BulkDeleter;
+
+    impl smart_ptr::Deleter for BulkDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            DELETE_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+
+        unsafe fn delete_slice<T>(_: *mut T, len: usize) {
+            SLICE_LEN.store(len, Ordering::SeqCst);
+        }
+    }
+
+    let mut storage = [1u8, 2, 3];
+    let buffer = unsafe { CVec::<u8, BulkDeleter>::from_raw(storage.as_mut_ptr(), 3) };
+    drop(buffer);
+    assert_eq!(SLICE_LEN.load(Ordering::SeqCst), 3);
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 0);
+
+    let buffer = unsafe { CVec::<u8, smart_ptr::AbortOnPanic<BulkDeleter>>::from_raw(storage.as_mut_ptr(), 2) };
+    drop(buffer);
+    assert_eq!(SLICE_LEN.load(Ordering::SeqCst), 2);
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_grow_realloc_buffer() {
+    use smart_ptr::cvec::{ReallocBuffer, VecDeleter};
+
+    let mut buffer = ReallocBuffer::<String, VecDeleter>::new();
+    assert!(buffer.as_ptr().is_null());
+    buffer.push(String::from("first"));
+    buffer.extend_from_slice(&[String::from("second"), String::from("third")]);
+    for _ in 0..10 {
+        buffer.push(String::from("next"));
+    }
+    assert_eq!(buffer.len(), 13);
+    assert!(buffer.capacity() >= 13);
+    assert_eq!(buffer[2], "third");
+    assert_eq!(buffer.try_reserve(usize::MAX), Err(smart_ptr::Error::AllocFailed));
+    assert_eq!(buffer.len(), 13);
+
+    let (ptr, len, capacity) = buffer.into_raw_parts();
+    let vec = unsafe { Vec::from_raw_parts(ptr, len, capacity) };
+    assert_eq!(vec[12], "next");
+
+    let mut zst = ReallocBuffer::<(), VecDeleter>::default();
+    zst.extend_from_slice(&[(), ()]);
+    assert_eq!(zst.len(), 2);
+}
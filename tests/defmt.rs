@@ -0,0 +1,20 @@
+#![cfg(feature = "defmt")]
+
+use smart_ptr::{Error, NullPtrError, OffsetPtr, StatefulUnique, Unique, UniqueConst};
+use smart_ptr::tagged::GenPtr;
+
+fn assert_format<T: ?Sized + defmt::Format>() {
+}
+
+#[test]
+fn should_implement_format() {
+    assert_format::<Error>();
+    assert_format::<NullPtrError>();
+    assert_format::<Unique<'static, u32, ()>>();
+    assert_format::<Unique<'static, str, ()>>();
+    assert_format::<Unique<'static, [u8], ()>>();
+    assert_format::<UniqueConst<'static, u32, ()>>();
+    assert_format::<StatefulUnique<'static, u32, fn(*mut u32)>>();
+    assert_format::<OffsetPtr<u32>>();
+    assert_format::<GenPtr<u32>>();
+}
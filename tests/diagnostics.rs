@@ -0,0 +1,68 @@
+#![cfg(feature = "diagnostics")]
+
+use smart_ptr::Unique;
+use smart_ptr::unique::Global;
+
+#[test]
+fn should_count_live_pointers_per_type() {
+    struct Session(#[allow(dead_code)] u32);
+
+    assert_eq!(smart_ptr::live_count::<Session>(), Some(0));
+    let first = Global::boxed(Session(1));
+    let second = Global::boxed(Session(2));
+    assert_eq!(smart_ptr::live_count::<Session>(), Some(2));
+    assert!(smart_ptr::diagnostics::live_counts().iter().any(|(name, count)| name.ends_with("Session") && *count == 2));
+
+    drop(first);
+    assert_eq!(smart_ptr::live_count::<Session>(), Some(1));
+    let raw = second.release();
+    assert_eq!(smart_ptr::live_count::<Session>(), Some(0));
+
+    let leaked = unsafe { Global::from_ptr_unchecked(raw.as_ptr()) };
+    core::mem::forget(leaked);
+    assert_eq!(smart_ptr::live_count::<Session>(), Some(1));
+
+    let mut value = 0u8;
+    let _borrowed = Unique::<[u8], ()>::from(core::slice::from_mut(&mut value));
+    assert_eq!(smart_ptr::live_count::<[u8]>(), Some(1));
+}
+
+#[test]
+fn should_not_count_borrowed_waker() {
+    #[derive(Clone)]
+    struct Task;
+
+    impl smart_ptr::waker::Wake for Task {
+        fn wake(&self) {
+        }
+    }
+
+    let waker = Global::boxed(Task).into_waker();
+    assert_eq!(smart_ptr::live_count::<Task>(), Some(0));
+
+    waker.wake_by_ref();
+    let clone = waker.clone();
+    assert_eq!(smart_ptr::live_count::<Task>(), Some(0));
+
+    drop(clone);
+    drop(waker);
+    assert_eq!(smart_ptr::live_count::<Task>(), Some(0));
+}
+
+#[test]
+fn should_count_pointer_exchanged_into_atomic_once() {
+    #[derive(Debug)]
+    struct Node;
+
+    let atomic = smart_ptr::AtomicUnique::empty();
+    let rejected = atomic.compare_exchange(core::ptr::NonNull::dangling().as_ptr(), Some(Global::boxed(Node))).expect_err("to reject");
+    assert_eq!(smart_ptr::live_count::<Node>(), Some(1));
+
+    assert!(atomic.compare_exchange(core::ptr::null_mut(), rejected).expect("to exchange").is_none());
+    assert_eq!(smart_ptr::live_count::<Node>(), Some(0));
+
+    let node = atomic.take();
+    assert_eq!(smart_ptr::live_count::<Node>(), Some(1));
+    drop(node);
+    assert_eq!(smart_ptr::live_count::<Node>(), Some(0));
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "diagnostics")]
+
+use smart_ptr::Unique;
+
+fn track<T: Default>() {
+    let mut value = T::default();
+    drop(Unique::<T, ()>::from(&mut value));
+}
+
+macro_rules! level {
+    ($name:ident => $next:ident) => {
+        fn $name<T: Default>() {
+            $next::<(T, u8)>();
+            $next::<(T, u16)>();
+        }
+    };
+}
+
+level!(level0 => level1);
+level!(level1 => level2);
+level!(level2 => level3);
+level!(level3 => level4);
+level!(level4 => level5);
+level!(level5 => level6);
+level!(level6 => level7);
+level!(level7 => level8);
+level!(level8 => level9);
+level!(level9 => level10);
+level!(level10 => track);
+
+#[test]
+fn should_report_untracked_type_once_capacity_is_exhausted() {
+    struct Session;
+
+    //2048 distinct types
+    level0::<()>();
+
+    let mut session = Session;
+    let ptr = Unique::<Session, ()>::from(&mut session);
+    assert_eq!(smart_ptr::live_count::<Session>(), None);
+    drop(ptr);
+    assert_eq!(smart_ptr::live_count::<((), u8)>(), None);
+    assert!(smart_ptr::diagnostics::live_counts().is_empty());
+}
@@ -0,0 +1,9 @@
+use smart_ptr::{Error, NullPtrError};
+
+#[test]
+fn should_display_error() {
+    assert_eq!(Error::from(NullPtrError), Error::Null);
+    assert_eq!(Error::Null.to_string(), "Pointer is null");
+    assert_eq!(Error::Misaligned { required: 8, found: 0x1001 }.to_string(), "Pointer 0x1001 is not aligned to 8 bytes");
+    assert_eq!(Error::AllocFailed.to_string(), "Memory allocation failed");
+}
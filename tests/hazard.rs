@@ -0,0 +1,96 @@
+use smart_ptr::hazard::HazardDomain;
+use smart_ptr::{AtomicUnique, Unique};
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static DELETE_COUNT: AtomicUsize = AtomicUsize::new(0);
+struct CountDeleter;
+
+impl smart_ptr::Deleter for CountDeleter {
+    unsafe fn delete<T: ?Sized>(_: *mut T) {
+        DELETE_COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+static mut VALUES: [u32; 4] = [0, 1, 2, 3];
+
+fn value(idx: usize) -> Unique<'static, u32, CountDeleter> {
+    unsafe {
+        Unique::new(core::ptr::addr_of_mut!(VALUES[idx]))
+    }
+}
+
+#[test]
+fn should_defer_deletion_of_protected_pointers() {
+    let domain = HazardDomain::<1, 2>::new();
+    let atomic = AtomicUnique::new(Some(value(0)));
+
+    let mut guard = domain.guard().expect("to have free slot");
+    assert!(domain.guard().is_none());
+    assert_eq!(unsafe { guard.protect(&atomic) }, Some(&0));
+
+    domain.retire(atomic.swap(Some(value(1))).expect("to have value"));
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 0);
+
+    //Second retire fills the list, triggering scan on the next one
+    domain.retire(atomic.swap(Some(value(2))).expect("to have value"));
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 0);
+    domain.retire(atomic.swap(Some(value(3))).expect("to have value"));
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 1);
+    assert_eq!(domain.retired_len(), 2);
+
+    guard.reset();
+    domain.reclaim();
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 3);
+    assert_eq!(domain.retired_len(), 0);
+
+    assert_eq!(unsafe { guard.protect(&atomic) }, Some(&3));
+    drop(guard);
+    assert!(domain.guard().is_some());
+}
+
+struct PanicDeleter;
+
+impl smart_ptr::Deleter for PanicDeleter {
+    unsafe fn delete<T: ?Sized>(_: *mut T) {
+        panic!("delete");
+    }
+}
+
+#[test]
+fn should_release_lock_on_panic_in_deleter() {
+    static mut VALUE: u32 = 0;
+
+    let domain = HazardDomain::<1, 2>::new();
+    domain.retire(unsafe { Unique::<u32, PanicDeleter>::new(core::ptr::addr_of_mut!(VALUE)) });
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| domain.reclaim()));
+    assert!(result.is_err());
+    //Entry is removed before deletion, so it is not deleted twice
+    assert_eq!(domain.retired_len(), 0);
+}
+
+#[test]
+fn should_allow_deleter_to_retire() {
+    static DOMAIN: HazardDomain<1, 2> = HazardDomain::new();
+    static RETIRED_COUNT: AtomicUsize = AtomicUsize::new(0);
+    static mut VALUE: u32 = 0;
+    static mut NESTED: u32 = 1;
+
+    struct RetireDeleter;
+
+    impl smart_ptr::Deleter for RetireDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            RETIRED_COUNT.fetch_add(1, Ordering::SeqCst);
+            DOMAIN.retire(Unique::<u32, ()>::new(core::ptr::addr_of_mut!(NESTED)));
+        }
+    }
+
+    DOMAIN.retire(unsafe { Unique::<u32, RetireDeleter>::new(core::ptr::addr_of_mut!(VALUE)) });
+    DOMAIN.reclaim();
+    assert_eq!(RETIRED_COUNT.load(Ordering::SeqCst), 1);
+    assert_eq!(DOMAIN.retired_len(), 1);
+
+    DOMAIN.reclaim();
+    assert_eq!(DOMAIN.retired_len(), 0);
+}
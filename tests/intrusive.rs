@@ -0,0 +1,86 @@
+use smart_ptr::intrusive::{RefCounted, RefCounter, IntrusiveShared};
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+struct Object {
+    counter: RefCounter,
+    value: u32,
+}
+
+unsafe impl RefCounted for Object {
+    fn inc(&self) {
+        self.counter.inc()
+    }
+
+    fn dec(&self) -> bool {
+        self.counter.dec()
+    }
+}
+
+#[test]
+fn should_delete_once_count_reaches_zero() {
+    static IS_DEALLOC: AtomicBool = AtomicBool::new(false);
+    pub struct MyDeleter;
+
+    impl smart_ptr::Deleter for MyDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            assert!(!IS_DEALLOC.swap(true, Ordering::SeqCst));
+        }
+    }
+
+    let mut object = Object {
+        counter: RefCounter::new(),
+        value: 5,
+    };
+
+    let ptr = unsafe { IntrusiveShared::<_, MyDeleter>::new(&mut object) };
+    let clone = ptr.clone();
+    assert!(ptr.ptr_eq(&clone));
+    assert_eq!(clone.value, 5);
+    assert_eq!(ptr.counter.get(), 2);
+
+    drop(ptr);
+    assert!(!IS_DEALLOC.load(Ordering::SeqCst));
+    assert_eq!(clone.counter.get(), 1);
+
+    drop(clone);
+    assert!(IS_DEALLOC.load(Ordering::SeqCst));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_handle_global_shared() {
+    let ptr = smart_ptr::intrusive::GlobalShared::boxed(Object {
+        counter: RefCounter::new(),
+        value: 10,
+    });
+    let clone = ptr.clone();
+    drop(ptr);
+    assert_eq!(clone.value, 10);
+    assert_eq!(clone.counter.get(), 1);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_pin_global_shared() {
+    use core::pin::Pin;
+    use smart_ptr::intrusive::GlobalShared;
+
+    let ptr = GlobalShared::pin(Object {
+        counter: RefCounter::new(),
+        value: 1,
+    });
+    let address = ptr.as_ref().get_ref() as *const Object;
+    let clone = ptr.clone();
+    assert_eq!(clone.value, 1);
+    assert_eq!(clone.counter.get(), 2);
+    drop(ptr);
+
+    let unpinned = GlobalShared::boxed(Object {
+        counter: RefCounter::new(),
+        value: 2,
+    });
+    let pinned: Pin<GlobalShared<Object>> = unpinned.into();
+    assert_eq!(pinned.value, 2);
+    assert_eq!(clone.as_ref().get_ref() as *const Object, address);
+}
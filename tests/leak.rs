@@ -0,0 +1,60 @@
+#![cfg(feature = "leak-tracking")]
+
+use smart_ptr::Unique;
+
+fn is_reported(address: usize, type_name: &str) -> bool {
+    smart_ptr::leak_report().iter().any(|leak| leak.address == address && leak.type_name == type_name)
+}
+
+#[test]
+fn should_report_only_live_pointers() {
+    let mut first = 0u64;
+    let mut second = 0u64;
+
+    let ptr = Unique::<u64, ()>::from(&mut first);
+    let first_address = ptr.get() as usize;
+    assert!(is_reported(first_address, "u64"));
+    drop(ptr);
+    assert!(!is_reported(first_address, "u64"));
+
+    let ptr = Unique::<u64, ()>::from(&mut second);
+    let second_address = ptr.get() as usize;
+    assert!(is_reported(second_address, "u64"));
+    ptr.release();
+    assert!(!is_reported(second_address, "u64"));
+}
+
+#[test]
+fn should_report_leaked_pointers() {
+    let ptr = Unique::<str, smart_ptr::GlobalDeleter>::from(String::from("leak").into_boxed_str());
+    let address = ptr.get() as *mut u8 as usize;
+    core::mem::forget(ptr);
+
+    let report = smart_ptr::leak_report();
+    let leak = report.iter().find(|leak| leak.address == address).expect("to report leak");
+    assert_eq!(leak.type_name, "str");
+    assert!(leak.to_string().starts_with("str at 0x"));
+}
+
+#[test]
+fn should_report_pointer_under_converted_type() {
+    let mut array = [1u8, 2];
+    let ptr = Unique::<[u8; 2], ()>::from(&mut array);
+    let address = ptr.get() as usize;
+    assert!(is_reported(address, "[u8; 2]"));
+
+    let ptr = smart_ptr::unsize!(ptr => [u8]);
+    assert!(!is_reported(address, "[u8; 2]"));
+    assert!(is_reported(address, "[u8]"));
+    drop(ptr);
+    assert!(!is_reported(address, "[u8]"));
+
+    let mut value = 1u32;
+    let ptr = Unique::<u32, ()>::from(&mut value);
+    let address = ptr.get() as usize;
+    let ptr = ptr.try_cast::<i32>().expect("to cast");
+    assert!(!is_reported(address, "u32"));
+    assert!(is_reported(address, "i32"));
+    drop(ptr);
+    assert!(!is_reported(address, "i32"));
+}
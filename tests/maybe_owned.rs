@@ -0,0 +1,23 @@
+#![cfg(feature = "alloc")]
+
+use smart_ptr::{GlobalDeleter, MaybeOwned};
+use smart_ptr::unique::Global;
+
+#[test]
+fn should_promote_borrowed_to_owned() {
+    let value = [1u32, 2];
+    let ptr = MaybeOwned::<[u32], GlobalDeleter>::from(&value[..]);
+    assert!(!ptr.is_owned());
+    assert_eq!(*ptr, [1, 2]);
+
+    let owned = ptr.into_owned();
+    assert_ne!(owned.get() as *const u32, value.as_ptr());
+    assert_eq!(*owned, [1, 2]);
+
+    let mut ptr = MaybeOwned::from(owned);
+    assert!(ptr.is_owned());
+    let address = ptr.as_ref().as_ptr();
+    ptr.to_mut()[0] = 0;
+    assert_eq!(ptr.as_ref().as_ptr(), address);
+    assert_eq!(ptr, MaybeOwned::Owned(Global::from_vec(vec![0, 2])));
+}
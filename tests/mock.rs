@@ -0,0 +1,45 @@
+#![cfg(feature = "test-util")]
+
+use smart_ptr::{CVec, GlobalDeleter, Unique};
+use smart_ptr::mock::{self, MockDeleter};
+
+#[test]
+fn should_record_deletions() {
+    mock::reset();
+
+    let ptr = unsafe { Unique::<String, MockDeleter<GlobalDeleter>>::new(Box::into_raw(Box::new(String::from("owned")))) };
+    let address = ptr.get() as usize;
+    drop(ptr);
+
+    let mut storage = [1u8, 2, 3];
+    drop(unsafe { CVec::<u8, MockDeleter>::from_raw(storage.as_mut_ptr(), 3) });
+
+    let deletions = mock::deletions();
+    assert_eq!(deletions.len(), 2);
+    assert_eq!(deletions[0].address, address);
+    assert_eq!(deletions[0].type_name, core::any::type_name::<String>());
+    assert_eq!(deletions[0].order, 0);
+    assert_eq!(deletions[1].address, storage.as_ptr() as usize);
+    assert_eq!(deletions[1].type_name, "[u8]");
+    assert_eq!(deletions[1].order, 1);
+    mock::assert_deletions(2);
+    assert!(mock::deletions().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Double free")]
+fn should_detect_double_free() {
+    let mut value = 1u32;
+    drop(unsafe { Unique::<u32, MockDeleter>::new(&mut value) });
+    drop(unsafe { Unique::<u32, MockDeleter>::new(&mut value) });
+    mock::assert_deletions(2);
+}
+
+#[test]
+#[should_panic(expected = "Unexpected number of deletions")]
+fn should_detect_missing_deletion() {
+    let mut value = 1u32;
+    let ptr = unsafe { Unique::<u32, MockDeleter>::new(&mut value) };
+    ptr.release();
+    mock::assert_deletions(1);
+}
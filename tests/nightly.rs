@@ -0,0 +1,33 @@
+#![cfg(feature = "nightly")]
+
+use smart_ptr::Unique;
+
+fn call_twice<F: FnMut(u32) -> u32>(mut cb: F) -> u32 {
+    cb(1) + cb(2)
+}
+
+#[test]
+fn should_call_owned_closure() {
+    let mut total = 0;
+    let mut cb = |value: u32| {
+        total += value;
+        total
+    };
+    let cb = Unique::<_, ()>::from(&mut cb);
+    assert_eq!(call_twice(cb), 4);
+    assert_eq!(total, 3);
+
+    let mut multiply = |value: u32| value * 2;
+    let multiply = Unique::<dyn Fn(u32) -> u32, ()>::from(&mut multiply as &mut dyn Fn(u32) -> u32);
+    assert_eq!(multiply(4), 8);
+    assert_eq!(call_twice(&multiply), 6);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Pointer is misaligned")]
+fn should_panic_on_misaligned_pointer() {
+    let mut buffer = [0u64; 2];
+    let misaligned = unsafe { (buffer.as_mut_ptr() as *mut u8).add(1) } as *mut u64;
+    let _ = unsafe { Unique::<u64, ()>::new(misaligned) };
+}
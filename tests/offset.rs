@@ -0,0 +1,54 @@
+use smart_ptr::OffsetPtr;
+
+use core::{mem, ptr};
+
+#[repr(C)]
+struct Segment {
+    values: [u32; 4],
+    current: OffsetPtr<u32>,
+}
+
+#[test]
+fn should_remain_valid_after_relocation() {
+    let mut segment = Segment {
+        values: [1, 2, 3, 4],
+        current: OffsetPtr::null(),
+    };
+    assert!(segment.current.is_null());
+    assert!(segment.current.get().is_null());
+
+    segment.current.set(&segment.values[2]);
+    assert!(!segment.current.is_null());
+    assert_eq!(segment.current.get(), &mut segment.values[2] as *mut u32);
+
+    let mut relocated = mem::MaybeUninit::<Segment>::uninit();
+    let relocated = unsafe {
+        ptr::copy_nonoverlapping(&segment as *const Segment, relocated.as_mut_ptr(), 1);
+        relocated.assume_init_mut()
+    };
+
+    assert_eq!(relocated.current.get(), &mut relocated.values[2] as *mut u32);
+    assert_eq!(unsafe { relocated.current.as_ref() }, Some(&3));
+
+    relocated.current.set(ptr::null());
+    assert!(relocated.current.is_null());
+}
+
+#[test]
+fn should_point_to_itself() {
+    #[repr(C)]
+    struct Node {
+        next: OffsetPtr<Node>,
+        value: u32,
+    }
+
+    let mut node = Node {
+        next: OffsetPtr::null(),
+        value: 1,
+    };
+    node.next.set(&node);
+    assert_eq!(node.next.offset(), 0);
+    assert!(!node.next.is_null());
+    assert_eq!(node.next.get() as *const Node, &node as *const Node);
+    assert_eq!(unsafe { node.next.as_ref() }.map(|next| next.value), Some(1));
+}
@@ -0,0 +1,35 @@
+use smart_ptr::{OnceUnique, Unique};
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static DELETE_COUNT: AtomicUsize = AtomicUsize::new(0);
+struct CountDeleter;
+
+impl smart_ptr::Deleter for CountDeleter {
+    unsafe fn delete<T: ?Sized>(_: *mut T) {
+        DELETE_COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn should_set_once_and_teardown() {
+    static CELL: OnceUnique<u32, CountDeleter> = OnceUnique::new();
+    static mut FIRST: u32 = 1;
+    static mut SECOND: u32 = 2;
+
+    assert!(CELL.get().is_none());
+    assert!(!unsafe { CELL.teardown() });
+
+    let first = unsafe { Unique::new(core::ptr::addr_of_mut!(FIRST)) };
+    let second = unsafe { Unique::new(core::ptr::addr_of_mut!(SECOND)) };
+    assert!(CELL.set(first).is_ok());
+    let second = CELL.set(second).unwrap_err();
+    assert_eq!(*second, 2);
+    assert_eq!(CELL.get(), Some(&1));
+    assert_eq!(*CELL.get_or_init(|| second), 1);
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 1);
+
+    assert!(unsafe { CELL.teardown() });
+    assert!(CELL.get().is_none());
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,75 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_return_slot_on_drop() {
+    use smart_ptr::pool::Pool;
+
+    static DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug)]
+    struct Object(u64);
+
+    impl Drop for Object {
+        fn drop(&mut self) {
+            DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let pool = Pool::new(2);
+    assert_eq!(pool.capacity(), 2);
+
+    let first = pool.acquire(Object(1)).expect("to have free slot");
+    let second = pool.acquire(Object(2)).expect("to have free slot");
+    let third = pool.acquire(Object(3)).unwrap_err();
+    assert_eq!(third.0, 3);
+    drop(third);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 1);
+
+    assert_eq!(first.0, 1);
+    assert_eq!(second.0, 2);
+    drop(first);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 2);
+
+    let fourth = pool.acquire(Object(4)).expect("to have free slot");
+    assert_eq!(fourth.0, 4);
+    assert_eq!(second.0, 2);
+    drop(fourth);
+    drop(second);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn should_return_slot_to_static_pool_on_drop() {
+    use smart_ptr::pool::StaticPool;
+
+    static DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
+    static POOL: StaticPool<Object, 2> = StaticPool::new();
+
+    #[repr(align(16))]
+    struct Object(u8);
+
+    impl Drop for Object {
+        fn drop(&mut self) {
+            DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    assert_eq!(POOL.capacity(), 2);
+    let first = POOL.acquire(Object(1)).unwrap_or_else(|_| panic!("to have free slot"));
+    let second = POOL.acquire(Object(2)).unwrap_or_else(|_| panic!("to have free slot"));
+    assert_eq!(first.get() as usize % 16, 0);
+    let third = POOL.acquire(Object(3)).err().expect("to have no free slot");
+    assert_eq!(third.0, 3);
+    drop(third);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 1);
+
+    drop(first);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 2);
+    let fourth = POOL.acquire(Object(4)).unwrap_or_else(|_| panic!("to have free slot"));
+    assert_eq!(fourth.0, 4);
+    assert_eq!(second.0, 2);
+    drop(fourth);
+    drop(second);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 4);
+}
@@ -0,0 +1,76 @@
+use smart_ptr::{Deleter, PtrArray};
+
+use core::ptr;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static DELETED: AtomicUsize = AtomicUsize::new(0);
+static ARRAY_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+struct CountDeleter;
+
+impl Deleter for CountDeleter {
+    unsafe fn delete<T: ?Sized>(_: *mut T) {
+        DELETED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+struct ArrayDeleter;
+
+impl Deleter for ArrayDeleter {
+    unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+        ARRAY_SIZE.store(core::mem::size_of_val(&*ptr), Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn should_own_array_and_elements() {
+    let mut values = [1u32, 2, 3];
+    let mut array = [&mut values[0] as *mut u32, &mut values[1] as *mut u32, &mut values[2] as *mut u32, ptr::null_mut()];
+
+    let elems = unsafe { PtrArray::<u32, CountDeleter, ArrayDeleter>::from_null_terminated(array.as_mut_ptr()) };
+    assert_eq!(elems.len(), 3);
+    assert_eq!(*elems[1], 2);
+    assert_eq!((&elems).into_iter().map(|elem| **elem).sum::<u32>(), 6);
+    drop(elems);
+    assert_eq!(DELETED.load(Ordering::SeqCst), 3);
+    assert_eq!(ARRAY_SIZE.load(Ordering::SeqCst), 4 * core::mem::size_of::<*mut u32>());
+
+    let elems = unsafe { PtrArray::<u32, CountDeleter, ArrayDeleter>::new(array.as_mut_ptr(), 2) };
+    let mut iter = elems.into_iter();
+    let first = iter.next().expect("to have first element");
+    assert_eq!(*first, 1);
+    core::mem::forget(first);
+    assert_eq!(iter.len(), 1);
+    drop(iter);
+    assert_eq!(DELETED.load(Ordering::SeqCst), 4);
+    assert_eq!(ARRAY_SIZE.load(Ordering::SeqCst), 2 * core::mem::size_of::<*mut u32>());
+}
+
+#[test]
+fn should_delete_null_terminated_array() {
+    use smart_ptr::{NullTerminatedArrayDeleter, Unique};
+
+    static ELEM_DELETED: AtomicUsize = AtomicUsize::new(0);
+    static ARRAY_LEN: AtomicUsize = AtomicUsize::new(0);
+
+    struct ElemDeleter;
+
+    impl Deleter for ElemDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            ELEM_DELETED.fetch_add(1, Ordering::SeqCst);
+        }
+
+        unsafe fn delete_slice<T>(_: *mut T, len: usize) {
+            ARRAY_LEN.store(len, Ordering::SeqCst);
+        }
+    }
+
+    let mut values = [1u32, 2];
+    let mut array = [&mut values[0] as *mut u32, &mut values[1] as *mut u32, ptr::null_mut()];
+
+    let argv = unsafe { Unique::<*mut u32, NullTerminatedArrayDeleter<u32, ElemDeleter>>::new(array.as_mut_ptr()) };
+    assert_eq!(unsafe { **argv }, 1);
+    drop(argv);
+    assert_eq!(ELEM_DELETED.load(Ordering::SeqCst), 2);
+    assert_eq!(ARRAY_LEN.load(Ordering::SeqCst), 3);
+}
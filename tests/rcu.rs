@@ -0,0 +1,63 @@
+use smart_ptr::{RcuCell, Unique};
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn should_dispose_of_replaced_value() {
+    static DELETE_COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct CountDeleter;
+
+    impl smart_ptr::Deleter for CountDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            DELETE_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let mut first = 1u32;
+    let mut second = 2u32;
+    let mut third = 3u32;
+
+    let mut cell = RcuCell::new(unsafe { Unique::<_, CountDeleter>::new(&mut first) });
+    assert_eq!(*cell.read(), 1);
+    cell.store(unsafe { Unique::new(&mut second) });
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 1);
+    assert_eq!(*cell.read(), 2);
+
+    *cell.get_mut() = 5;
+    let old = cell.replace(unsafe { Unique::new(&mut third) });
+    assert_eq!(*old, 5);
+    drop(old);
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 2);
+
+    drop(cell);
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 3);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_wait_for_readers_across_threads() {
+    use smart_ptr::unique::Global;
+
+    let cell = std::sync::Arc::new(RcuCell::new(Global::boxed([0usize; 8])));
+
+    let readers = (0..4).map(|_| {
+        let cell = cell.clone();
+        std::thread::spawn(move || {
+            for _ in 0..1000 {
+                let snapshot = cell.read();
+                let first = snapshot[0];
+                assert!(snapshot.iter().all(|value| *value == first));
+            }
+        })
+    }).collect::<Vec<_>>();
+
+    for idx in 1..=100 {
+        cell.store(Global::boxed([idx; 8]));
+    }
+
+    for reader in readers {
+        reader.join().expect("to finish");
+    }
+
+    assert_eq!(*cell.read(), [100; 8]);
+}
@@ -0,0 +1,43 @@
+#![cfg(all(feature = "std", any(target_os = "linux", target_os = "macos")))]
+
+use smart_ptr::shm::SharedMemory;
+
+use std::ffi::CString;
+
+fn segment_name(suffix: &str) -> CString {
+    CString::new(format!("/smart-ptr-{}-{}", std::process::id(), suffix)).unwrap()
+}
+
+#[test]
+fn should_share_memory_between_mappings() {
+    let name = segment_name("share");
+    let owner = SharedMemory::create(&name, 4096).expect("to create segment");
+    assert_eq!(owner.len(), 4096);
+    assert_eq!(owner.name(), name.as_c_str());
+    assert!(SharedMemory::create(&name, 4096).is_err());
+
+    let peer = SharedMemory::open(&name, 4096).expect("to open segment");
+    unsafe {
+        assert_eq!(*owner.view::<u64>(8).expect("to fit"), 0);
+        *owner.view::<u64>(8).expect("to fit") = u64::MAX;
+        assert_eq!(*peer.view::<u64>(8).expect("to fit"), u64::MAX);
+
+        assert!(owner.view::<u64>(4090).is_none());
+        assert!(owner.view::<u64>(3).is_none());
+    }
+
+    drop(peer);
+    drop(owner);
+    assert!(SharedMemory::open(&name, 4096).is_err());
+}
+
+#[test]
+fn should_keep_segment_without_unlink() {
+    let name = segment_name("keep");
+    let mut owner = SharedMemory::create(&name, 64).expect("to create segment");
+    owner.set_unlink(false);
+    drop(owner);
+
+    let mut peer = SharedMemory::open(&name, 64).expect("to open segment");
+    peer.set_unlink(true);
+}
@@ -0,0 +1,124 @@
+use smart_ptr::stateful::{FnDeleter, StatefulUnique};
+
+use core::ffi::c_void;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static FREED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe extern "C" fn count_free(ptr: *mut c_void) {
+    FREED.fetch_add(*(ptr as *mut usize), Ordering::SeqCst);
+}
+
+#[test]
+fn should_delete_with_runtime_function() {
+    let mut value = 2usize;
+    let ptr = unsafe { StatefulUnique::new(&mut value as *mut usize, FnDeleter(count_free)) };
+    assert_eq!(*ptr, 2);
+    drop(ptr);
+    assert_eq!(FREED.load(Ordering::SeqCst), 2);
+
+    let mut value = 3usize;
+    let ptr = unsafe { StatefulUnique::new(&mut value as *mut usize, FnDeleter(count_free)) };
+    let (raw, deleter) = ptr.release();
+    assert_eq!(FREED.load(Ordering::SeqCst), 2);
+    assert_eq!(raw.as_ptr(), &mut value as *mut usize);
+    drop(unsafe { StatefulUnique::new(raw.as_ptr(), deleter) });
+    assert_eq!(FREED.load(Ordering::SeqCst), 5);
+
+    assert!(unsafe { StatefulUnique::<usize, _>::from_ptr(core::ptr::null_mut(), FnDeleter(count_free)) }.is_none());
+}
+
+#[test]
+fn should_delete_with_closure() {
+    let mut deleted = Vec::new();
+    let mut value = 1u32;
+    {
+        let deleted = &mut deleted;
+        let ptr = unsafe { smart_ptr::Unique::with_deleter(&mut value as *mut u32, move |ptr| deleted.push(*ptr)) };
+        assert_eq!(*ptr, 1);
+    }
+    assert_eq!(deleted, [1]);
+}
+
+#[test]
+fn should_delete_with_context() {
+    unsafe extern "C" fn release(ptr: *mut c_void, ctx: *mut c_void) {
+        (*(ctx as *mut Vec<u32>)).push(*(ptr as *mut u32));
+    }
+
+    let mut released = Vec::<u32>::new();
+    let mut first = 1u32;
+    let mut second = 2u32;
+    let deleter = smart_ptr::stateful::CtxDeleter::new(release, &mut released as *mut Vec<u32> as *mut c_void);
+    unsafe {
+        drop(StatefulUnique::new(&mut first as *mut u32, deleter));
+        drop(StatefulUnique::new(&mut second as *mut u32, deleter));
+    }
+    assert_eq!(released, [1, 2]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_delete_with_dyn_deleter() {
+    use smart_ptr::{DynDeleter, DeallocOnly};
+
+    static DELETED: AtomicUsize = AtomicUsize::new(0);
+
+    struct CountDeleter;
+
+    impl DynDeleter for CountDeleter {
+        unsafe fn delete_dyn(&self, ptr: *mut (), layout: core::alloc::Layout) {
+            DELETED.fetch_add(layout.size(), Ordering::SeqCst);
+            DeallocOnly.delete_dyn(ptr, layout);
+        }
+    }
+
+    let deleters: Vec<Box<dyn DynDeleter>> = vec![Box::new(DeallocOnly), Box::new(CountDeleter)];
+    for deleter in deleters {
+        let buffer = Box::into_raw(vec![0u8; 3].into_boxed_slice());
+        let ptr = unsafe { StatefulUnique::new(buffer, deleter) };
+        assert_eq!(ptr.len(), 3);
+    }
+
+    let deleter: Box<dyn DynDeleter> = Box::new(CountDeleter);
+    let ptr = unsafe { StatefulUnique::new(Box::into_raw(Box::new(0u64)), deleter) };
+    assert_eq!(*ptr, 0);
+    drop(ptr);
+    assert_eq!(DELETED.load(Ordering::SeqCst), 11);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_dispose_split_block_once() {
+    use std::rc::Rc;
+    use smart_ptr::Deleter;
+
+    static DELETED: AtomicUsize = AtomicUsize::new(0);
+
+    struct CountDeleter;
+
+    impl Deleter for CountDeleter {
+        unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+            DELETED.fetch_add(1, Ordering::SeqCst);
+            smart_ptr::boxed_deleter(ptr);
+        }
+    }
+
+    let counter = Rc::new(());
+    let buffer = vec![counter.clone(), counter.clone(), counter.clone()].into_boxed_slice();
+    let buffer = unsafe { smart_ptr::Unique::<[Rc<()>], CountDeleter>::new(Box::into_raw(buffer)) };
+
+    let (left, right) = buffer.split_at(1);
+    assert_eq!(left.len(), 1);
+    assert_eq!(right.len(), 2);
+    let (middle, last) = right.split_at(1);
+
+    drop(left);
+    assert_eq!(Rc::strong_count(&counter), 3);
+    drop(last);
+    assert_eq!(Rc::strong_count(&counter), 2);
+    assert_eq!(DELETED.load(Ordering::SeqCst), 0);
+    drop(middle);
+    assert_eq!(Rc::strong_count(&counter), 1);
+    assert_eq!(DELETED.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "stats")]
+
+use smart_ptr::unique::Global;
+
+#[test]
+fn should_count_allocations_and_frees() {
+    let before = smart_ptr::stats();
+    let ptr = Global::boxed(1u64);
+    let slice = Global::<[u32]>::new_zeroed_slice(4);
+    let after = smart_ptr::stats();
+    assert!(after.allocations >= before.allocations + 2);
+    assert!(after.allocated_bytes >= before.allocated_bytes + 8 + 16);
+
+    drop(ptr);
+    drop(slice);
+    let after = smart_ptr::stats();
+    assert!(after.deallocations >= before.deallocations + 2);
+    assert!(after.deallocated_bytes >= before.deallocated_bytes + 8 + 16);
+}
+
+#[test]
+fn should_not_count_zero_sized() {
+    let ptr = Global::boxed(());
+    drop(ptr);
+
+    let stats = smart_ptr::stats();
+    assert!(stats.live() <= stats.allocations);
+    assert!(stats.live_bytes() <= stats.allocated_bytes);
+}
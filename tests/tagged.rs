@@ -0,0 +1,62 @@
+use smart_ptr::tagged::GenPtr;
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn should_detect_aba_reuse() {
+    use smart_ptr::tagged::{AtomicTaggedPtr, TaggedPtr};
+
+    let mut first = 1u64;
+    let mut second = 2u64;
+    let first = &mut first as *mut u64;
+    let second = &mut second as *mut u64;
+
+    let atomic = AtomicTaggedPtr::new(first);
+    let stale = atomic.load();
+    assert_eq!(stale.tag(), 0);
+
+    let old = atomic.swap(second);
+    assert_eq!(old, stale);
+    let old = atomic.swap(first);
+    assert_eq!(old.ptr(), second);
+    assert_eq!(old.tag(), 1);
+
+    let actual = atomic.compare_exchange(stale, second).unwrap_err();
+    assert_eq!(actual.ptr(), first);
+    assert_eq!(actual.tag(), 2);
+
+    let wrapped = TaggedPtr::new(first, u16::MAX).next(second);
+    assert_eq!(wrapped.tag(), 0);
+    assert_eq!(TaggedPtr::<u64>::from_bits(wrapped.into_bits()), wrapped);
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn should_reject_address_beyond_48_bits() {
+    use smart_ptr::tagged::TaggedPtr;
+
+    let mut value = 1u64;
+    let tagged = TaggedPtr::try_new(&mut value as *mut u64, 3).expect("to fit into 48 bits");
+    assert_eq!(tagged.ptr(), &mut value as *mut u64);
+    assert_eq!(tagged.tag(), 3);
+
+    let wide = (1usize << 56) as *mut u64;
+    assert!(TaggedPtr::try_new(wide, 0).is_none());
+    let fallback = GenPtr::new(wide, 0);
+    assert_eq!(fallback.ptr(), wide);
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+#[should_panic(expected = "Address doesn't fit into 48 bits")]
+fn should_panic_on_address_beyond_48_bits() {
+    let _ = smart_ptr::tagged::TaggedPtr::new((1usize << 56) as *mut u64, 0);
+}
+
+#[test]
+fn should_pack_double_word() {
+    let mut value = 0u8;
+    let ptr = GenPtr::new(&mut value as *mut u8, 7);
+    assert_eq!(core::mem::align_of::<GenPtr<u8>>(), 2 * core::mem::size_of::<usize>());
+    assert_eq!(ptr.next(ptr.ptr()).generation(), 8);
+    assert_eq!(GenPtr::from_bits(ptr.into_bits()), ptr);
+}
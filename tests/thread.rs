@@ -0,0 +1,44 @@
+use smart_ptr::thread::{AssertSend, AssertSync};
+use smart_ptr::Unique;
+
+use core::ffi::c_void;
+
+fn is_send<T: Send>(_: &T) {}
+fn is_sync<T: Sync>(_: &T) {}
+
+#[test]
+fn should_assert_thread_safety() {
+    let mut value = 1u32;
+    let ptr = unsafe { Unique::<c_void, ()>::new(&mut value as *mut u32 as *mut c_void) };
+    let address = ptr.get();
+
+    let ptr = unsafe { AssertSync::new(AssertSend::new(ptr)) };
+    is_send(&*ptr);
+    is_sync(&ptr);
+    assert_eq!(ptr.get(), address);
+
+    let ptr = ptr.into_inner();
+    let address = address as usize;
+    std::thread::scope(|scope| {
+        scope.spawn(move || {
+            assert_eq!(ptr.get() as usize, address);
+        });
+    });
+}
+
+#[test]
+fn should_bind_pointer_to_thread() {
+    use smart_ptr::thread::LocalUnique;
+
+    let mut value = 1u32;
+    let mut ptr = LocalUnique::from(Unique::<u32, ()>::from(&mut value));
+    *ptr += 1;
+    assert_eq!(*ptr, 2);
+
+    let ptr = unsafe { ptr.into_unique() };
+    is_send(&ptr);
+    drop(ptr);
+    assert_eq!(value, 2);
+
+    assert!(unsafe { LocalUnique::<u32, ()>::from_ptr(core::ptr::null_mut()) }.is_none());
+}
@@ -4,7 +4,7 @@ extern crate alloc;
 use smart_ptr::{unique, Unique};
 
 use core::ptr;
-use core::sync::atomic::{AtomicBool, Ordering};
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 #[test]
 fn should_drop_without_dealloc() {
@@ -29,7 +29,7 @@ fn should_drop_without_dealloc() {
 #[test]
 fn should_dealloc() {
     static IS_DEALLOC: AtomicBool = AtomicBool::new(false);
-    pub struct MyDeleter<'a>(&'a mut bool);
+    pub struct MyDeleter<'a>(#[allow(dead_code)] &'a mut bool);
 
     impl<'a> smart_ptr::Deleter for MyDeleter<'a> {
         unsafe fn delete<T: ?Sized>(_: *mut T) {
@@ -60,6 +60,34 @@ fn should_fail_on_null() {
     }
 }
 
+#[test]
+fn should_return_error_on_null() {
+    let mut value = true;
+    unsafe {
+        assert_eq!(unique::Unique::<bool, ()>::try_new(ptr::null_mut()).unwrap_err(), smart_ptr::NullPtrError);
+        assert!(*unique::Unique::<bool, ()>::try_new(&mut value).expect("to be non-null"));
+    }
+}
+
+#[test]
+fn should_verify_alignment() {
+    let mut value = 0u64;
+    let ptr = &mut value as *mut u64;
+    unsafe {
+        assert!(unique::Unique::<u64, ()>::new_aligned(ptr).is_ok());
+        assert_eq!(unique::Unique::<u64, ()>::new_aligned(ptr::null_mut()).unwrap_err(), smart_ptr::Error::Null);
+
+        let misaligned = (ptr as *mut u8).add(1) as *mut u64;
+        match unique::Unique::<u64, ()>::new_aligned(misaligned) {
+            Err(smart_ptr::Error::Misaligned { required, found }) => {
+                assert_eq!(required, core::mem::align_of::<u64>());
+                assert_eq!(found, misaligned as usize);
+            },
+            _ => panic!("Misaligned pointer is accepted"),
+        }
+    }
+}
+
 #[test]
 fn should_handle_mut_ref() {
     let mut test = false;
@@ -71,6 +99,7 @@ fn should_handle_mut_ref() {
 
 #[cfg(feature = "alloc")]
 #[test]
+#[allow(clippy::useless_format)]
 fn should_handle_global_alloc_string() {
     let text = Box::new(alloc::format!("test"));
     let ptr: unique::Global<_> = text.into();
@@ -82,9 +111,958 @@ fn should_handle_global_alloc_string() {
 
 #[cfg(feature = "alloc")]
 #[test]
+#[allow(clippy::useless_format)]
 fn should_handle_global_alloc_boxed_str() {
     let text = alloc::format!("test").into_boxed_str();
     let ptr: unique::Global<_> = text.into();
+    let clone = ptr.clone();
     assert_eq!(ptr.as_ref(), "test");
+    assert_ne!(ptr.get() as *mut u8, clone.get() as *mut u8);
+    drop(ptr);
+    assert_eq!(clone.as_ref(), "test");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_clone_global_slice() {
+    let ptr = unique::Global::<[String]>::from_vec(vec![String::from("first"), String::from("second")]);
+    let clone = ptr.clone();
+    assert_eq!(ptr, clone);
+    drop(ptr);
+    assert_eq!(*clone, [String::from("first"), String::from("second")]);
+
+    let empty = unique::Global::<[String]>::from_vec(Vec::new());
+    assert_eq!(empty.clone().len(), 0);
+}
+
+#[test]
+fn should_clone_with_deleter() {
+    static DELETE_COUNT: AtomicUsize = AtomicUsize::new(0);
+    static mut COPIES: [u32; 2] = [0; 2];
+    static COPY_IDX: AtomicUsize = AtomicUsize::new(0);
+
+    pub struct CopyDeleter;
+
+    impl smart_ptr::Deleter for CopyDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            DELETE_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    impl smart_ptr::CloneWith<u32> for CopyDeleter {
+        unsafe fn clone_ptr(ptr: *const u32) -> *mut u32 {
+            let copy = ptr::addr_of_mut!(COPIES[COPY_IDX.fetch_add(1, Ordering::SeqCst)]);
+            *copy = *ptr;
+            copy
+        }
+    }
+
+    let mut value = 5u32;
+    let ptr = unsafe { Unique::<u32, CopyDeleter>::new(&mut value) };
+    let clone = ptr.clone();
+    assert_eq!(ptr, clone);
+    assert_ne!(ptr.get(), clone.get());
+    drop(ptr);
+    drop(clone);
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_not_allocate_zero_sized_value() {
+    static DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Clone)]
+    struct Zst;
+
+    impl Drop for Zst {
+        fn drop(&mut self) {
+            DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let ptr = unique::Global::boxed(Zst);
+    assert_eq!(ptr.get(), ptr::NonNull::<Zst>::dangling().as_ptr());
+    let clone = ptr.clone();
+    assert_eq!(clone.get(), ptr::NonNull::<Zst>::dangling().as_ptr());
+    drop(ptr);
+    drop(clone);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 2);
+
+    let ptr = unique::Global::boxed(Zst).into_boxed();
+    drop(ptr);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 3);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_dealloc_without_drop() {
+    static DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct Droppy(#[allow(dead_code)] u64);
+
+    impl Drop for Droppy {
+        fn drop(&mut self) {
+            DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let ptr = Box::into_raw(Box::new(Droppy(1)));
+    let ptr = unsafe { Unique::<_, smart_ptr::DeallocOnly>::new(ptr) };
+    drop(ptr);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 0);
+
+    let ptr = Box::into_raw(vec![1u8, 2, 3].into_boxed_slice());
+    let ptr = unsafe { Unique::<[u8], smart_ptr::DeallocOnly>::new(ptr) };
+    assert_eq!(ptr.as_ref(), [1, 2, 3]);
+}
+
+#[test]
+fn should_drop_in_place() {
+    static DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct Droppy(u64);
+
+    impl Drop for Droppy {
+        fn drop(&mut self) {
+            DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let mut storage = core::mem::MaybeUninit::new(Droppy(5));
+    let ptr = unsafe { Unique::<_, smart_ptr::DropInPlace>::new(storage.as_mut_ptr()) };
+    assert_eq!(ptr.0, 5);
+    drop(ptr);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn should_initialize_uninit() {
+    let mut storage = core::mem::MaybeUninit::<u64>::uninit();
+    let ptr: Unique::<_, ()> = (&mut storage).into();
+    let ptr = ptr.write(10);
+    assert_eq!(*ptr, 10);
+    drop(ptr);
+
+    let ptr: Unique::<_, ()> = (&mut storage).into();
+    let ptr = unsafe { ptr.write_zeroed() };
+    assert_eq!(*ptr, 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_initialize_global_uninit() {
+    let ptr = unique::Global::<String>::new_uninit();
+    let ptr = ptr.write(String::from("test"));
+    assert_eq!(ptr.as_ref(), "test");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_allocate_zeroed() {
+    let ptr = unique::Global::<u64>::new_zeroed();
+    assert_eq!(unsafe { *ptr.assume_init() }, 0);
+
+    let slice = unique::Global::<[u32]>::new_zeroed_slice(16);
+    assert_eq!(slice.len(), 16);
+    assert!(slice.iter().all(|elem| unsafe { elem.assume_init() } == 0));
+
+    let empty = unique::Global::<[u32]>::new_zeroed_slice(0);
+    assert_eq!(empty.len(), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_initialize_uninit_slice() {
+    let mut buffer = unique::Global::<[String]>::new_uninit_slice(2);
+    assert_eq!(buffer.len(), 2);
+    buffer[0].write(String::from("first"));
+    buffer[1].write(String::from("second"));
+
+    let buffer = unsafe { buffer.assume_init() };
+    assert_eq!(*buffer, [String::from("first"), String::from("second")]);
+
+    let mut storage = [core::mem::MaybeUninit::<u8>::uninit(); 2];
+    let mut buffer = unique::Unique::<[core::mem::MaybeUninit<u8>], ()>::from(&mut storage[..]);
+    buffer[0].write(1);
+    buffer[1].write(2);
+    let buffer = unsafe { buffer.assume_init() };
+    assert_eq!(*buffer, [1, 2]);
+}
+
+#[test]
+fn should_delete_through_abort_on_panic() {
+    static IS_DEALLOC: AtomicBool = AtomicBool::new(false);
+    pub struct MyDeleter;
+
+    impl smart_ptr::Deleter for MyDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            IS_DEALLOC.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let mut value = 1u8;
+    let ptr = unsafe { Unique::<_, smart_ptr::AbortOnPanic<MyDeleter>>::new(&mut value) };
+    drop(ptr);
+    assert!(IS_DEALLOC.load(Ordering::SeqCst));
+}
+
+#[test]
+fn should_delete_with_layout() {
+    static SIZE: AtomicUsize = AtomicUsize::new(0);
+    static ALIGN: AtomicUsize = AtomicUsize::new(0);
+    pub struct LayoutDeleter;
+
+    impl smart_ptr::Deleter for LayoutDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            unreachable!();
+        }
+
+        unsafe fn delete_with_layout<T: ?Sized>(_: *mut T, layout: core::alloc::Layout) {
+            SIZE.store(layout.size(), Ordering::SeqCst);
+            ALIGN.store(layout.align(), Ordering::SeqCst);
+        }
+    }
+
+    let mut value = [0u32; 3];
+    let ptr = unsafe { Unique::<[u32], smart_ptr::AbortOnPanic<LayoutDeleter>>::new(&mut value[..]) };
+    drop(ptr);
+    assert_eq!(SIZE.load(Ordering::SeqCst), 12);
+    assert_eq!(ALIGN.load(Ordering::SeqCst), 4);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn should_delegate_error() {
+    #[derive(Debug)]
+    struct Inner;
+
+    impl core::fmt::Display for Inner {
+        fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            fmt.write_str("inner")
+        }
+    }
+
+    impl std::error::Error for Inner {}
+
+    #[derive(Debug)]
+    struct Outer(Inner);
+
+    impl core::fmt::Display for Outer {
+        fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            fmt.write_str("outer")
+        }
+    }
+
+    impl std::error::Error for Outer {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    let error: Box<dyn std::error::Error> = Box::new(unique::Global::boxed(Outer(Inner)));
+    assert_eq!(error.to_string(), "outer");
+    assert_eq!(error.source().expect("to have source").to_string(), "inner");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn should_delegate_io() {
+    use std::io::{Read, Write, Seek, SeekFrom};
+
+    let mut stream = unique::Global::boxed(std::io::Cursor::new(Vec::new()));
+    stream.write_all(b"test").expect("to write");
+    assert_eq!(stream.stream_position().expect("to get position"), 4);
+    stream.seek(SeekFrom::Start(1)).expect("to seek");
+
+    let mut output = String::new();
+    stream.read_to_string(&mut output).expect("to read");
+    assert_eq!(output, "est");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_delegate_future() {
+    use core::future::Future;
+    use core::task::{Context, Poll, Waker};
+
+    struct Ready(u32);
+
+    impl Future for Ready {
+        type Output = u32;
+
+        fn poll(self: core::pin::Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+            Poll::Ready(self.0)
+        }
+    }
+
+    let mut ctx = Context::from_waker(Waker::noop());
+
+    let mut future = unique::Global::boxed(Ready(1));
+    assert_eq!(core::pin::Pin::new(&mut future).poll(&mut ctx), Poll::Ready(1));
+
+    let mut future = unique::Global::pin(async {
+        2
+    });
+    assert_eq!(future.as_mut().poll(&mut ctx), Poll::Ready(2));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_convert_global_into_shared() {
+    let rc = unique::Global::boxed(String::from("rc")).into_rc();
+    assert_eq!(rc.as_str(), "rc");
+
+    let text: Box<str> = "arc".into();
+    let arc = unique::Global::from(text).into_arc();
+    assert_eq!(&*arc, "arc");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_convert_global_via_traits() {
+    let rc: std::rc::Rc<u32> = unique::Global::boxed(1).into();
+    assert_eq!(*rc, 1);
+
+    let arc: std::sync::Arc<u32> = unique::Global::boxed(2).into();
+    assert_eq!(*arc, 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_handle_byte_buffer() {
+    let mut buffer = unique::Global::<[u8]>::from_vec(vec![0u8; 4]);
+    assert_eq!(buffer.as_bytes(), [0, 0, 0, 0]);
+
+    buffer.fill(1);
+    assert_eq!(buffer.as_bytes(), [1, 1, 1, 1]);
+
+    buffer.copy_from_slice(&[1, 2, 3, 4]);
+    buffer.as_bytes_mut()[0] = 5;
+    assert_eq!(buffer.as_bytes(), [5, 2, 3, 4]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn should_construct_array_in_place() {
+    static DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct Droppy(usize);
+
+    impl Drop for Droppy {
+        fn drop(&mut self) {
+            DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let slice = unique::Global::<[Droppy]>::boxed_slice(3, Droppy);
+    assert_eq!(slice.len(), 3);
+    assert_eq!(slice[2].0, 2);
+    drop(slice);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 3);
+
+    let result = std::panic::catch_unwind(|| {
+        unique::Global::<[Droppy; 4]>::boxed_array(|idx| match idx {
+            2 => panic!("Cannot initialize"),
+            idx => Droppy(idx),
+        })
+    });
+    assert!(result.is_err());
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 5);
+
+    let empty = unique::Global::<[Droppy; 0]>::boxed_array(Droppy);
+    assert_eq!(empty.len(), 0);
+}
+
+#[cfg(feature = "debug-guards")]
+#[test]
+#[should_panic(expected = "Unique is used after it was released or deleted")]
+fn should_panic_on_use_after_delete() {
+    let mut value = 0u32;
+    let mut ptr = core::mem::ManuallyDrop::new(Unique::<u32, ()>::from(&mut value));
+    unsafe {
+        core::ptr::drop_in_place(&mut *ptr);
+    }
+    ptr.get();
+}
+
+#[cfg(feature = "debug-guards")]
+#[test]
+fn should_not_delete_twice() {
+    static DELETE_COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct MyDeleter;
+
+    impl smart_ptr::Deleter for MyDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            DELETE_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let mut value = 0u32;
+    let mut ptr = core::mem::ManuallyDrop::new(unsafe { Unique::<u32, MyDeleter>::new(&mut value) });
+    unsafe {
+        core::ptr::drop_in_place(&mut *ptr);
+        core::ptr::drop_in_place(&mut *ptr);
+    }
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 1);
+}
+
+#[cfg(feature = "debug-guards")]
+#[test]
+fn should_not_delete_twice_by_multiple_owners() {
+    static DELETE_COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct MyDeleter;
+
+    impl smart_ptr::Deleter for MyDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            DELETE_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let mut value = 0u32;
+    let first = unsafe { Unique::<u32, MyDeleter>::new(&mut value) };
+    let second = unsafe { Unique::<u32, MyDeleter>::new(first.get()) };
+    drop(first);
+    drop(second);
+    assert_eq!(DELETE_COUNT.load(Ordering::SeqCst), 1);
+}
+
+#[cfg(feature = "debug-guards")]
+#[test]
+#[should_panic(expected = "Unique is used after it was released or deleted")]
+fn should_panic_on_use_after_delete_by_other_owner() {
+    struct MyDeleter;
+
+    impl smart_ptr::Deleter for MyDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+        }
+    }
+
+    let mut value = 0u32;
+    let first = unsafe { Unique::<u32, MyDeleter>::new(&mut value) };
+    let second = core::mem::ManuallyDrop::new(unsafe { Unique::<u32, MyDeleter>::new(first.get()) });
+    drop(first);
+    second.get();
+}
+
+#[test]
+fn should_reborrow_without_deleter() {
+    let mut value = 1u32;
+    let mut first = Unique::<u32, ()>::from(&mut value);
+    {
+        let mut second = Unique::<u32, ()>::from(&mut *first);
+        *second += 1;
+    }
+    assert_eq!(*first, 2);
+}
+
+#[cfg(feature = "debug-guards")]
+#[test]
+fn should_allow_to_own_released_pointer_again() {
+    let mut value = 0u32;
+    let ptr = Unique::<u32, ()>::from(&mut value).release();
+    let ptr = unsafe { Unique::<u32, ()>::new(ptr.as_ptr()) };
+    assert_eq!(*ptr, 0);
+    drop(ptr);
+
+    //Zero sized values share address
+    let mut units = [(), ()];
+    let [first, second] = &mut units;
+    let zst = Unique::<(), ()>::from(first);
+    drop(Unique::<(), ()>::from(second));
+    assert_eq!(*zst, ());
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn should_view_pod_as_bytes() {
+    let mut value = 0u32;
+    let mut ptr = Unique::<u32, ()>::from(&mut value);
+    ptr.as_bytes_mut().copy_from_slice(&0xdead_beefu32.to_ne_bytes());
+    assert_eq!(ptr.as_bytes(), 0xdead_beefu32.to_ne_bytes());
+
+    let ptr = ptr.cast_pod::<[i32; 1]>();
+    assert_eq!(ptr[0] as u32, 0xdead_beef);
+    let ptr = ptr.cast_pod::<f32>();
+    assert_eq!(ptr.to_bits(), 0xdead_beef);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_unsize_global() {
+    trait Animal {
+        fn name(&self) -> &str;
+    }
+
+    struct Cat(String);
+
+    impl Animal for Cat {
+        fn name(&self) -> &str {
+            &self.0
+        }
+    }
+
+    let ptr = unique::Global::boxed(Cat(String::from("Cat")));
+    let address = ptr.get() as *mut u8;
+    let ptr: unique::Global<dyn Animal> = smart_ptr::unsize!(ptr => dyn Animal);
+    assert_eq!(ptr.get() as *mut u8, address);
+    assert_eq!(ptr.name(), "Cat");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn should_own_platform_strings() {
+    use std::ffi::{OsStr, OsString};
+    use std::path::{Path, PathBuf};
+
+    let string = unique::Global::<OsStr>::from(OsString::from("test"));
+    let clone = string.clone();
+    assert_eq!(string.as_os_str(), "test");
+    assert_eq!(string.into_os_string(), OsString::from("test"));
+    assert_eq!(clone.to_os_string(), OsString::from("test"));
+
+    let empty = unique::Global::<OsStr>::from(OsString::new());
+    assert_eq!(empty.clone().as_os_str(), "");
+
+    let path = unique::Global::<Path>::from(PathBuf::from("dir").join("file"));
+    assert_eq!(path.file_name(), Some(OsStr::new("file")));
+    assert_eq!(path.to_path_buf(), Path::new("dir").join("file"));
+    assert_eq!(path.into_path_buf(), Path::new("dir").join("file"));
+}
+
+#[test]
+fn should_convert_into_non_null() {
+    let mut value = 1u8;
+    let ptr = Unique::<u8, ()>::from(&mut value);
+    let non_null = ptr.as_non_null();
+    assert_eq!(non_null.as_ptr(), ptr.get());
+
+    let released: ptr::NonNull<u8> = ptr.into();
+    assert_eq!(released, non_null);
+}
+
+#[test]
+fn should_store_owned_without_lifetime() {
+    static IS_DEALLOC: AtomicBool = AtomicBool::new(false);
+    pub struct MyDeleter;
+
+    impl smart_ptr::Deleter for MyDeleter {
+        unsafe fn delete<T: ?Sized>(ptr: *mut T) {
+            drop(Box::from_raw(ptr));
+            IS_DEALLOC.store(true, Ordering::SeqCst);
+        }
+    }
+
+    struct Handle {
+        ptr: smart_ptr::Owned<u32, MyDeleter>,
+    }
+
+    fn create() -> Handle {
+        Handle {
+            ptr: unsafe { smart_ptr::Owned::new(Box::into_raw(Box::new(1))) },
+        }
+    }
+
+    let handle = create();
+    assert_eq!(*handle.ptr, 1);
+    drop(handle);
+    assert!(IS_DEALLOC.load(Ordering::SeqCst));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn should_allocate_with_custom_allocator() {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Default)]
+    struct CountingAlloc;
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            LIVE.fetch_add(1, Ordering::SeqCst);
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            LIVE.fetch_sub(1, Ordering::SeqCst);
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    let ptr = unique::Global::boxed_with::<CountingAlloc>(vec![1u8, 2, 3]);
+    let empty = unique::Global::boxed_with::<CountingAlloc>(());
+    assert_eq!(LIVE.load(Ordering::SeqCst), 1);
+    assert_eq!(*ptr, [1, 2, 3]);
+    drop(ptr);
+    drop(empty);
+    assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_report_alloc_failure() {
+    use smart_ptr::Error;
+    use unique::Global;
+
+    let ptr = Global::try_boxed(String::from("test")).expect("to allocate");
+    assert_eq!(*ptr.try_clone().expect("to clone"), "test");
+    let slice = Global::<[u16]>::try_boxed_slice(3, |idx| idx as u16).expect("to allocate");
+    assert_eq!(*slice.try_clone().expect("to clone"), [0, 1, 2]);
+    let array = Global::<[u8; 8]>::try_boxed_array(|idx| idx as u8).expect("to allocate");
+    assert_eq!(array[7], 7);
+    let zeroed = Global::<u64>::try_new_zeroed().expect("to allocate");
+    assert_eq!(unsafe { *zeroed.assume_init() }, 0);
+
+    assert_eq!(Global::<[u32]>::try_new_uninit_slice(usize::MAX).unwrap_err(), Error::AllocFailed);
+    assert_eq!(Global::<[u32]>::try_new_zeroed_slice(usize::MAX / 2).unwrap_err(), Error::AllocFailed);
+    assert_eq!(Global::<[u8]>::try_new_zeroed_pages(usize::MAX).unwrap_err(), Error::AllocFailed);
+    assert_eq!(Global::<[u8]>::try_boxed_slice(isize::MAX as usize, |_| 0).unwrap_err(), Error::AllocFailed);
+    assert!(Global::try_boxed_aligned(1u8, 64).is_ok());
+    assert!(Global::try_boxed_pages(1u8).is_ok());
+}
+
+#[test]
+fn should_emplace_into_storage() {
+    use core::mem::MaybeUninit;
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counted(u32);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let mut storage = MaybeUninit::uninit();
+    let address = storage.as_ptr() as usize;
+    let value = Unique::emplace(&mut storage, Counted(1));
+    assert_eq!(value.0, 1);
+    assert_eq!(value.get() as usize, address);
+    drop(value);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+
+    let mut bytes = [MaybeUninit::<u8>::uninit(); 7];
+    let value = Unique::emplace_in(&mut bytes[..3], Counted(2));
+    assert_eq!(value.err().map(|value| value.0), Some(2));
+    assert_eq!(DROPS.load(Ordering::SeqCst), 2);
+
+    let value = Unique::emplace_in(&mut bytes, Counted(3)).unwrap_or_else(|_| panic!("to fit"));
+    assert_eq!(value.get() as usize & 3, 0);
+    assert_eq!(value.0, 3);
+    drop(value);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 3);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_recycle_allocation() {
+    use unique::Global;
+
+    let ptr = Global::boxed(String::from("request"));
+    let address = ptr.get() as usize;
+    let ptr = ptr.recycle(vec![1u8, 2]);
+    assert_eq!(ptr.get() as usize, address);
+    assert_eq!(*ptr, [1, 2]);
+
+    let ptr = ptr.recycle([5usize; 64]);
+    assert_eq!(ptr[63], 5);
+    let ptr = ptr.recycle(1u8);
+    assert_eq!(*ptr, 1);
+    let ptr = ptr.recycle(());
+    let ptr = ptr.recycle(String::from("last"));
+    assert_eq!(*ptr, "last");
+}
+
+#[test]
+fn should_delegate_operators() {
+    let mut first = 6u32;
+    let mut second = 3i32;
+    let mut first = Unique::<u32, ()>::from(&mut first);
+    let mut second = Unique::<i32, ()>::from(&mut second);
+
+    assert_eq!(&first + 1, 7);
+    assert_eq!(&first - 1, 5);
+    assert_eq!(&first * 2, 12);
+    assert_eq!(&first / 4, 1);
+    assert_eq!(&first % 4, 2);
+    assert_eq!(&first & 2, 2);
+    assert_eq!(&first | 1, 7);
+    assert_eq!(&first ^ 2, 4);
+    assert_eq!(&first << 1u8, 12);
+    assert_eq!(&first >> 1u8, 3);
+    assert_eq!(!&first, !6);
+    assert_eq!(-&second, -3);
+
+    first += 4;
+    first <<= 1u8;
+    second *= -2;
+    assert_eq!(*first, 20);
+    assert_eq!(*second, -6);
+}
+
+#[test]
+fn should_compare_with_plain_values() {
+    let mut number = 4u32;
+    let mut float = 0.5f64;
+    let mut text = *b"expected";
+    let mut bytes = [1u8, 2, 3];
+    let mut pair = (1u8, 'a');
+
+    let number = Unique::<u32, ()>::from(&mut number);
+    let float = Unique::<f64, ()>::from(&mut float);
+    let text = Unique::<str, ()>::from(core::str::from_utf8_mut(&mut text).unwrap());
+    let bytes = Unique::<[u8], ()>::from(&mut bytes[..]);
+    let pair = Unique::<(u8, char), ()>::from(&mut pair);
+
+    assert!(number == 4);
+    assert!(number < 5);
+    assert!(number >= 4);
+    assert!(float > 0.25);
+    assert!(text == "expected");
+    assert!("expected" == text);
+    assert!(text < "extra");
+    assert!(bytes == [1, 2, 3]);
+    assert!(bytes == [1u8, 2, 3][..]);
+    let expected: &[u8] = &[1, 2, 3];
+    assert!(bytes == expected);
+    assert!(pair == (1, 'a'));
+    assert!(pair < (1, 'b'));
+    #[cfg(feature = "alloc")]
+    {
+        let expected = String::from("expected");
+        assert!(text == expected);
+        assert!(bytes == vec![1u8, 2, 3]);
+    }
+}
+
+#[test]
+fn should_convert_back_into_reference() {
+    fn generic<'a, D: smart_ptr::Deleter>(ptr: Unique<'a, [u8], D>) -> Unique<'a, [u8], D> {
+        ptr
+    }
+
+    let mut buffer = [0u8; 4];
+    let ptr = generic(Unique::<[u8], ()>::from(&mut buffer[..]));
+    let slice = ptr.into_mut();
+    slice[0] = 1;
+    assert_eq!(buffer, [1, 0, 0, 0]);
+}
+
+#[test]
+fn should_swap_values_keeping_addresses() {
+    let mut first = [1u8; 4];
+    let mut second = [2u8; 4];
+    let mut first = Unique::<[u8; 4], ()>::from(&mut first);
+    let mut second = unsafe { Unique::<[u8; 4], smart_ptr::DropInPlace>::new(&mut second) };
+    let addresses = (first.get(), second.get());
+
+    first.swap_values(&mut second);
+    assert_eq!(*first, [2; 4]);
+    assert_eq!(*second, [1; 4]);
+    assert_eq!((first.get(), second.get()), addresses);
+}
+
+#[test]
+fn should_wrap_static_data() {
+    use smart_ptr::NoopDeleter;
+
+    static mut BUFFER: [u8; 4] = [0; 4];
+
+    let buffer: &'static mut [u8; 4] = unsafe { &mut *core::ptr::addr_of_mut!(BUFFER) };
+    let mut ptr: Unique<'static, [u8; 4], NoopDeleter> = Unique::from_static(buffer);
+    ptr[0] = 1;
+    drop(ptr);
+    assert_eq!(unsafe { BUFFER[0] }, 1);
+}
+
+#[test]
+fn should_create_dangling_zero_sized() {
+    #[repr(align(32))]
+    struct Marker;
+
+    let first = Unique::<Marker, _>::dangling();
+    let second = Unique::<Marker, _>::dangling();
+    assert_eq!(first.get() as usize, 32);
+    assert_eq!(first.get(), second.get());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_own_zero_sized_without_allocation() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let guard = unique::Global::zst(Guard);
+    assert_eq!(guard.get() as usize, core::mem::align_of::<Guard>());
+    assert_eq!(DROPS.load(Ordering::SeqCst), 0);
+    drop(guard);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+}
+
+mod ffi_repr {
+    use smart_ptr::{assert_ffi_repr, Unique, UniqueConst, DropInPlace};
+    use smart_ptr::thread::LocalUnique;
+
+    pub struct Foo {
+        _value: u64,
+    }
+
+    assert_ffi_repr!(
+        Unique<'static, Foo, DropInPlace>,
+        UniqueConst<'static, Foo, ()>,
+        LocalUnique<'static, Foo, ()>,
+    );
+}
+
+#[cfg(feature = "alloc")]
+mod opaque {
+    use smart_ptr::{declare_opaque, assert_ffi_repr, Unique, GlobalDeleter};
+    use smart_ptr::unique::Global;
+
+    pub struct Counter {
+        value: u32,
+    }
+
+    declare_opaque! {
+        pub CounterHandle(Counter, GlobalDeleter);
+        create = test_counter_create(value: u32) => Global::boxed(Counter { value });
+        destroy = test_counter_destroy;
+    }
+
+    declare_opaque!(pub PlainHandle(Counter, GlobalDeleter););
+
+    assert_ffi_repr!(CounterHandle, PlainHandle);
+
+    #[test]
+    fn should_export_opaque_handle() {
+        let mut handle = test_counter_create(1);
+        handle.value += 1;
+        let ptr: Unique<'static, Counter, GlobalDeleter> = handle.into();
+        assert_eq!(ptr.value, 2);
+
+        let handle = CounterHandle::from(ptr);
+        test_counter_destroy(Some(handle));
+        test_counter_destroy(None);
+
+        let plain = PlainHandle::from(Global::boxed(Counter { value: 3 }));
+        assert_eq!(plain.value, 3);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_take_unique_ownership_of_refcounted_pointers() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let shared = Arc::new(String::from("shared"));
+    let weak = Arc::downgrade(&shared);
+    let shared = Unique::from_arc(shared).unwrap_err();
+    drop(weak);
+    let mut value = Unique::from_arc(shared).expect("to be unique");
+    value.push('!');
+    let shared = value.into_arc();
+    assert_eq!(*shared, "shared!");
+
+    let copy = shared.clone();
+    let shared = Unique::from_arc(shared).unwrap_err();
+    drop(copy);
+    let value = Unique::from_arc(shared).expect("to be unique");
+    drop(value);
+
+    let shared: Rc<[u8]> = Rc::from(&[1u8, 2][..]);
+    let value = Unique::from_rc(shared).expect("to be unique");
+    assert_eq!(*value, [1, 2]);
+}
+
+#[test]
+fn should_check_cast_layout() {
+    #[repr(C, align(8))]
+    struct Pair(u32, u32);
+
+    let mut value = Pair(1, 2);
+    let ptr = Unique::<Pair, ()>::from(&mut value);
+    assert_eq!(ptr.checked_cast::<u64>(), Some(ptr.get() as *mut u64));
+    assert_eq!(ptr.checked_cast::<u32>(), Some(ptr.get() as *mut u32));
+    assert_eq!(ptr.checked_cast::<[u32; 3]>(), None);
+    assert_eq!(ptr.checked_cast::<u128>(), None);
+
+    let misaligned = unsafe { Unique::<[u8], ()>::new(ptr::slice_from_raw_parts_mut((ptr.get() as *mut u8).add(1), 7)) };
+    assert_eq!(misaligned.checked_cast::<u32>(), None);
+    assert!(misaligned.checked_cast::<[u8; 4]>().is_some());
+    misaligned.release();
+
+    match ptr.try_cast::<[u32; 2]>() {
+        Ok(ptr) => panic!("Cast of {:p} should fail due to alignment", ptr),
+        Err(ptr) => match ptr.try_cast::<u64>() {
+            Ok(ptr) => assert_eq!(ptr.checked_cast::<[u32; 2]>().map(|ptr| unsafe { *ptr }), Some([1, 2])),
+            Err(ptr) => panic!("Cast of {:p} should succeed", ptr),
+        },
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_be_generic_over_owned_pointers() {
+    use smart_ptr::OwnedPtr;
+    use smart_ptr::intrusive::{GlobalShared, RefCounter};
+
+    fn round_trip<P: OwnedPtr>(ptr: P) -> P {
+        let raw = ptr.get();
+        let ptr = ptr.release();
+        assert_eq!(ptr.as_ptr(), raw);
+        unsafe {
+            P::from_non_null(ptr)
+        }
+    }
+
+    let value = round_trip(Box::new(1u32));
+    assert_eq!(*value, 1);
+
+    let value = round_trip(unique::Global::<str>::from(String::from("owned").into_boxed_str()));
+    assert_eq!(value, "owned");
+
+    let value = round_trip(smart_ptr::UniqueConst::from(unique::Global::boxed(2u8)));
+    assert_eq!(*value, 2);
+
+    let value = round_trip(GlobalShared::boxed(RefCounter::new()));
+    assert_eq!(value.as_ref().get(), 1);
+}
+
+#[test]
+fn should_delete_slice_with_layout() {
+    static DELETED_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+    struct SliceDeleter;
+
+    impl smart_ptr::Deleter for SliceDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            panic!("Slice must be deleted with layout");
+        }
+
+        unsafe fn delete_with_layout<T: ?Sized>(_: *mut T, layout: core::alloc::Layout) {
+            DELETED_SIZE.store(layout.size(), Ordering::SeqCst);
+        }
+
+        unsafe fn delete_slice<T>(_: *mut T, _: usize) {
+            panic!("Slice must be deleted with layout");
+        }
+    }
+
+    let mut values = [1u32, 2, 3];
+    let ptr = unsafe { Unique::<[u32], SliceDeleter>::new(&mut values[..]) };
     drop(ptr);
+    assert_eq!(DELETED_SIZE.load(Ordering::SeqCst), 12);
 }
@@ -0,0 +1,72 @@
+use smart_ptr::{Deleter, UniqueConst};
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+#[test]
+fn should_delete_read_only_pointer() {
+    static IS_DELETED: AtomicBool = AtomicBool::new(false);
+    struct MyDeleter;
+
+    impl Deleter for MyDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            IS_DELETED.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let value = String::from("const");
+    let ptr = unsafe { UniqueConst::<String, MyDeleter>::new(&value) };
+    assert_eq!(ptr.get(), &value as *const String);
+    assert_eq!(ptr.len(), 5);
+    assert_eq!(ptr.to_string(), "const");
+    drop(ptr);
+    assert!(IS_DELETED.load(Ordering::SeqCst));
+
+    assert!(unsafe { UniqueConst::<String, MyDeleter>::from_ptr(core::ptr::null()) }.is_none());
+}
+
+#[test]
+fn should_compare_read_only_pointers() {
+    let first = 1u8;
+    let second = 1u8;
+    let mut first = UniqueConst::<u8, ()>::from(&first);
+    let mut second = UniqueConst::<u8, ()>::from(&second);
+    assert_eq!(first, second);
+    let address = first.get();
+    first.swap(&mut second);
+    assert_eq!(second.release().as_ptr() as *const u8, address);
+}
+
+#[test]
+fn should_convert_mutability() {
+    let mut value = 1u32;
+    let ptr = smart_ptr::Unique::<u32, ()>::from(&mut value);
+    let address = ptr.get();
+
+    let ptr: UniqueConst<u32, ()> = ptr.into();
+    assert_eq!(ptr.get(), address as *const u32);
+
+    let mut ptr = unsafe { ptr.into_mut() };
+    *ptr = 2;
+    assert_eq!(ptr.get(), address);
+    drop(ptr);
+    assert_eq!(value, 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_share_arc_reference() {
+    use std::sync::Arc;
+
+    let shared = Arc::new(String::from("shared"));
+    let first = UniqueConst::from_arc(shared.clone());
+    let second = UniqueConst::from_arc(shared.clone());
+    assert_eq!(Arc::strong_count(&shared), 3);
+    assert_eq!(*first, "shared");
+
+    drop(first);
+    assert_eq!(Arc::strong_count(&shared), 2);
+    let second = second.into_arc();
+    assert!(Arc::ptr_eq(&second, &shared));
+    drop(second);
+    assert_eq!(Arc::strong_count(&shared), 1);
+}
@@ -0,0 +1,144 @@
+use smart_ptr::utils::CallOnDrop;
+
+#[test]
+fn should_call_on_drop_unless_cancelled() {
+    let mut counter = 0;
+    {
+        let _guard = CallOnDrop::new(|| counter += 1);
+    }
+    assert_eq!(counter, 1);
+
+    {
+        let guard = CallOnDrop::new(|| counter += 1);
+        guard.cancel();
+    }
+    assert_eq!(counter, 1);
+}
+
+#[test]
+fn should_pass_value_to_guard_on_drop() {
+    use smart_ptr::utils::{guard, ScopeGuard};
+
+    let mut output = Vec::new();
+    {
+        let mut guard = guard(&mut output, |output| output.push(2));
+        guard.push(1);
+    }
+    assert_eq!(output, [1, 2]);
+
+    let mut guard = ScopeGuard::new(vec![1], |_| panic!("Should not be called"));
+    guard.push(2);
+    assert_eq!(ScopeGuard::into_inner(guard), [1, 2]);
+
+    let guard = ScopeGuard::new(vec![1], |_| panic!("Should not be called"));
+    ScopeGuard::dismiss(guard);
+}
+
+#[test]
+fn should_defer_until_end_of_scope() {
+    use core::cell::Cell;
+
+    let counter = Cell::new(0);
+    {
+        smart_ptr::defer! {
+            assert_eq!(counter.get(), 1);
+            counter.set(2);
+        }
+        smart_ptr::defer!(counter.set(1));
+        assert_eq!(counter.get(), 0);
+    }
+    assert_eq!(counter.get(), 2);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn should_call_guard_depending_on_unwind() {
+    use smart_ptr::utils::{guard_on_success, guard_on_unwind};
+
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static UNWIND_COUNT: AtomicUsize = AtomicUsize::new(0);
+    static SUCCESS_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    {
+        let _unwind = guard_on_unwind((), |_| { UNWIND_COUNT.fetch_add(1, Ordering::SeqCst); });
+        let _success = guard_on_success((), |_| { SUCCESS_COUNT.fetch_add(1, Ordering::SeqCst); });
+    }
+    assert_eq!(UNWIND_COUNT.load(Ordering::SeqCst), 0);
+    assert_eq!(SUCCESS_COUNT.load(Ordering::SeqCst), 1);
+
+    let result = std::panic::catch_unwind(|| {
+        let _unwind = guard_on_unwind((), |_| { UNWIND_COUNT.fetch_add(1, Ordering::SeqCst); });
+        let _success = guard_on_success((), |_| { SUCCESS_COUNT.fetch_add(1, Ordering::SeqCst); });
+        panic!("rollback");
+    });
+    assert!(result.is_err());
+    assert_eq!(UNWIND_COUNT.load(Ordering::SeqCst), 1);
+    assert_eq!(SUCCESS_COUNT.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn should_delete_all_remaining_pointers() {
+    use smart_ptr::Deleter;
+    use smart_ptr::utils::{delete_all, DeleteGuard};
+
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static DELETED: AtomicUsize = AtomicUsize::new(0);
+    static DELETED_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+    struct CountDeleter;
+
+    impl Deleter for CountDeleter {
+        unsafe fn delete<T: ?Sized>(_: *mut T) {
+            DELETED.fetch_add(1, Ordering::SeqCst);
+        }
+
+        unsafe fn delete_with_layout<T: ?Sized>(ptr: *mut T, layout: core::alloc::Layout) {
+            DELETED_SIZE.fetch_add(layout.size(), Ordering::SeqCst);
+            Self::delete::<T>(ptr)
+        }
+    }
+
+    let mut values = [1u32, 2, 3];
+    let [first, second, third] = values.each_mut().map(|value| value as *mut u32);
+
+    unsafe {
+        delete_all::<u32, CountDeleter, _>([first, core::ptr::null_mut(), second]);
+    }
+    assert_eq!(DELETED.load(Ordering::SeqCst), 2);
+    assert_eq!(DELETED_SIZE.load(Ordering::SeqCst), 8);
+
+    let mut guard = unsafe {
+        DeleteGuard::<u32, CountDeleter, _>::new([first, second, third])
+    };
+    assert_eq!(guard.next(), Some(first));
+    drop(guard);
+    assert_eq!(DELETED.load(Ordering::SeqCst), 4);
+
+    let mut guard = unsafe {
+        DeleteGuard::<u32, CountDeleter, _>::new([first, second, third])
+    };
+    assert_eq!(guard.next(), Some(first));
+    assert_eq!(guard.into_inner().count(), 2);
+    assert_eq!(DELETED.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn should_call_once_on_drop_with_owned_values() {
+    use std::sync::mpsc;
+
+    let (sender, receiver) = mpsc::channel();
+    let values = vec![1, 2];
+    {
+        let _guard = CallOnDrop::new(move || sender.send(values).expect("to send"));
+    }
+    assert_eq!(receiver.recv().expect("to receive"), [1, 2]);
+
+    let (sender, receiver) = mpsc::channel::<u8>();
+    let guard = CallOnDrop::new(move || sender.send(1).expect("to send"));
+    let cb = guard.into_inner();
+    assert!(receiver.try_recv().is_err());
+    cb();
+    assert_eq!(receiver.recv().expect("to receive"), 1);
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "alloc")]
+
+use smart_ptr::intrusive::{GlobalShared, RefCounted, RefCounter};
+use smart_ptr::unique::Global;
+use smart_ptr::waker::Wake;
+
+use std::sync::Arc;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Clone)]
+struct Flag {
+    wakes: Arc<AtomicUsize>,
+}
+
+impl Wake for Flag {
+    fn wake(&self) {
+        self.wakes.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn should_convert_unique_into_waker() {
+    let wakes = Arc::new(AtomicUsize::new(0));
+    let waker = Global::boxed(Flag { wakes: wakes.clone() }).into_waker();
+    assert_eq!(Arc::strong_count(&wakes), 2);
+
+    waker.wake_by_ref();
+    let clone = waker.clone();
+    assert_eq!(Arc::strong_count(&wakes), 3);
+    clone.wake();
+    assert_eq!(Arc::strong_count(&wakes), 2);
+    assert_eq!(wakes.load(Ordering::SeqCst), 2);
+
+    drop(waker);
+    assert_eq!(Arc::strong_count(&wakes), 1);
+}
+
+#[test]
+fn should_convert_shared_into_waker() {
+    struct Task {
+        counter: RefCounter,
+        wakes: AtomicUsize,
+    }
+
+    unsafe impl RefCounted for Task {
+        fn inc(&self) {
+            self.counter.inc()
+        }
+
+        fn dec(&self) -> bool {
+            self.counter.dec()
+        }
+    }
+
+    impl Wake for Task {
+        fn wake(&self) {
+            self.wakes.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let task = GlobalShared::boxed(Task {
+        counter: RefCounter::new(),
+        wakes: AtomicUsize::new(0),
+    });
+    let waker = task.clone().into_waker();
+    let clone = waker.clone();
+    assert_eq!(task.counter.get(), 3);
+    assert!(waker.will_wake(&clone));
+
+    clone.wake();
+    waker.wake_by_ref();
+    assert_eq!(task.wakes.load(Ordering::SeqCst), 2);
+    assert_eq!(task.counter.get(), 2);
+
+    drop(waker);
+    assert_eq!(task.counter.get(), 1);
+}
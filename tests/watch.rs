@@ -0,0 +1,37 @@
+#![cfg(feature = "alloc")]
+
+use smart_ptr::unique::Global;
+use smart_ptr::watch::Watched;
+
+#[test]
+fn should_invalidate_observers_on_replace() {
+    let mut owner = Watched::new(Global::boxed(1u32));
+    let first = owner.observe();
+    let copy = first.clone();
+    assert!(first.is_alive());
+    assert_eq!(first.get().map(|ptr| ptr.as_ptr()), Some(owner.get()));
+
+    let previous = owner.replace(Global::boxed(2));
+    assert_eq!(*previous, 1);
+    assert!(!first.is_alive());
+    assert!(copy.get().is_none());
+
+    let second = owner.observe();
+    assert!(second.is_alive());
+    *owner += 1;
+    assert_eq!(*owner, 3);
+
+    let inner = owner.into_inner();
+    assert_eq!(*inner, 3);
+    assert!(second.get().is_none());
+}
+
+#[test]
+fn should_invalidate_observers_on_drop() {
+    let owner = Watched::from(Global::boxed(String::from("owner")));
+    let observer = owner.observe();
+    assert_eq!(format!("{:?}", owner), "\"owner\"");
+
+    std::thread::spawn(move || drop(owner)).join().expect("to drop");
+    assert!(observer.get().is_none());
+}
@@ -0,0 +1,37 @@
+use smart_ptr::WideStrPtr;
+
+#[test]
+fn should_read_null_terminated_string() {
+    let mut buffer = [0x48u16, 0x69, 0xD83D, 0xDE00, 0];
+    let string = unsafe { WideStrPtr::<()>::new(buffer.as_mut_ptr()) };
+    assert_eq!(string.len(), 4);
+    assert!(!string.is_empty());
+    assert_eq!(string.as_slice_with_nul().len(), 5);
+    assert_eq!(string.to_string(), "Hi\u{1F600}");
+    assert_eq!(format!("{:?}", string), "\"Hi\u{1F600}\"");
+    assert_eq!(string.release().as_ptr(), buffer.as_mut_ptr());
+
+    let mut invalid = [0xD83Du16, 0];
+    let string = unsafe { WideStrPtr::<()>::new(invalid.as_mut_ptr()) };
+    assert_eq!(string.to_string(), "\u{FFFD}");
+
+    assert!(unsafe { WideStrPtr::<()>::from_ptr(core::ptr::null_mut()) }.is_none());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn should_convert_platform_string() {
+    use std::ffi::{OsStr, OsString};
+
+    let string = WideStrPtr::from("test\0ignored");
+    assert_eq!(string.len(), 4);
+    assert_eq!(string.as_slice(), "test".encode_utf16().collect::<Vec<_>>());
+    assert_eq!(unsafe { *string.as_ptr().add(4) }, 0);
+
+    let string = WideStrPtr::from(OsStr::new("path"));
+    assert_eq!(string.to_os_string(), OsString::from("path"));
+    assert_eq!(string, WideStrPtr::encode("path"));
+
+    let empty = WideStrPtr::encode("");
+    assert!(empty.is_empty());
+}